@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{convert::TryInto, str::FromStr};
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 use nuuid::{Rng, Uuid};
@@ -30,6 +30,37 @@ fn new_v4(c: &mut Criterion) {
     });
 }
 
+fn fill(c: &mut Criterion) {
+    const COUNT: usize = 10_000;
+    let mut group = c.benchmark_group("Generating UUIDs in bulk");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    let mut rng = Rng::new();
+    let mut uuids = vec![Uuid::nil(); COUNT];
+
+    group.bench_function("Nuuid::fill_v4", |b| {
+        b.iter(|| Uuid::fill_v4(&mut rng, black_box(&mut uuids)))
+    });
+    group.bench_function("Nuuid::new_v4_rng", |b| {
+        b.iter(|| {
+            for uuid in black_box(&mut uuids).iter_mut() {
+                *uuid = Uuid::new_v4_rng(&mut rng);
+            }
+        })
+    });
+
+    group.bench_function("Nuuid::fill_v7", |b| {
+        b.iter(|| Uuid::fill_v7(&mut rng, 0x17F22E279B0, black_box(&mut uuids)))
+    });
+    group.bench_function("Nuuid::new_v7", |b| {
+        b.iter(|| {
+            for uuid in black_box(&mut uuids).iter_mut() {
+                *uuid = Uuid::new_v7(0x17F22E279B0, rng.next_u32() as u16, rng.next_u64());
+            }
+        })
+    });
+    group.finish();
+}
+
 fn new_v5(c: &mut Criterion) {
     let mut group = c.benchmark_group("new_v5");
     group.throughput(Throughput::Elements(1));
@@ -84,6 +115,42 @@ fn to_str(c: &mut Criterion) {
     });
 }
 
+fn batch(c: &mut Criterion) {
+    const COUNT: usize = 10_000;
+    let mut group = c.benchmark_group("Formatting and parsing UUIDs in bulk");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    let mut uuids = vec![Uuid::nil(); COUNT];
+    Uuid::fill_v4(&mut Rng::new(), &mut uuids);
+    let mut buf = vec![0u8; COUNT * 37];
+    let input = Uuid::to_string_batch(&uuids);
+
+    group.bench_function("Nuuid::to_str_batch", |b| {
+        b.iter(|| {
+            Uuid::to_str_batch(black_box(&uuids), &mut buf);
+        })
+    });
+    group.bench_function("Nuuid::to_str", |b| {
+        b.iter(|| {
+            for (uuid, line) in black_box(&uuids).iter().zip(buf.chunks_exact_mut(37)) {
+                uuid.to_str((&mut line[..36]).try_into().unwrap());
+                line[36] = b'\n';
+            }
+        })
+    });
+
+    group.bench_function("Nuuid::parse_batch_into", |b| {
+        b.iter(|| Uuid::parse_batch_into(black_box(&input), &mut uuids))
+    });
+    group.bench_function("Nuuid::parse", |b| {
+        b.iter(|| {
+            for (line, uuid) in black_box(&input).lines().zip(uuids.iter_mut()) {
+                *uuid = Uuid::parse(line).unwrap();
+            }
+        })
+    });
+    group.finish();
+}
+
 fn variant(c: &mut Criterion) {
     let mut group = c.benchmark_group("UUIDs Variant");
     group.throughput(Throughput::Elements(1));
@@ -151,7 +218,7 @@ fn is_nil(c: &mut Criterion) {
 fn timestamp(c: &mut Criterion) {
     let mut group = c.benchmark_group("UUIDs timestamp");
     group.throughput(Throughput::Elements(1));
-    let time = Timestamp::from_rfc4122(12345678, 12345);
+    let time = Timestamp::from_gregorian_time(12345678, 12345);
     let bytes = *Uuid_::new_v1(time, b"654321").as_bytes();
     let uuid = Uuid::from_bytes(bytes);
     let uuid_ = Uuid_::from_bytes(bytes);
@@ -186,16 +253,18 @@ fn new_v1(c: &mut Criterion) {
         b.iter(|| Uuid::new_v1(ticks, counter, node))
     });
     group.bench_function("Uuid::new_v1", |b| {
-        b.iter(|| Uuid_::new_v1(Timestamp::from_rfc4122(ticks, counter), &node))
+        b.iter(|| Uuid_::new_v1(Timestamp::from_gregorian_time(ticks, counter), &node))
     });
 }
 
 criterion_group!(
     benches, //
     new_v4,
+    fill,
     new_v5,
     from_str,
     to_str,
+    batch,
     variant,
     version,
     mixed_endian,
@@ -0,0 +1,271 @@
+//! Formatting and parsing many UUIDs at once
+use core::str::from_utf8_unchecked_mut;
+
+use hex_simd::{AsciiCase::Lower, Out};
+
+use crate::{ParseErrorKind, ParseUuidError, Uuid, UUID_HYPHENS, UUID_STR_LENGTH};
+
+/// Length of a hyphenated UUID and its newline
+const LINE_LENGTH: usize = UUID_STR_LENGTH + 1;
+
+/// Number of UUIDs hex encoded or decoded in one go
+const CHUNK: usize = 64;
+
+/// Move the byte index of an error on a line by `offset`,
+/// so it refers to the whole input.
+fn offset_error(e: ParseUuidError, offset: usize) -> ParseUuidError {
+    let kind = match e.kind {
+        ParseErrorKind::InvalidCharacter { found, index } => ParseErrorKind::InvalidCharacter {
+            found,
+            index: index + offset,
+        },
+        ParseErrorKind::MisplacedHyphen { index } => ParseErrorKind::MisplacedHyphen {
+            index: index + offset,
+        },
+        ParseErrorKind::MissingHyphen { found, index } => ParseErrorKind::MissingHyphen {
+            found,
+            index: index + offset,
+        },
+        ParseErrorKind::NonAscii { index } => ParseErrorKind::NonAscii {
+            index: index + offset,
+        },
+        kind => kind,
+    };
+    ParseUuidError::new(kind)
+}
+
+/// Parse `line`, starting at byte `offset` of the input, on its own.
+fn parse_line(line: &str, offset: usize) -> Result<Uuid, ParseUuidError> {
+    Uuid::parse(line).map_err(|e| offset_error(e, offset))
+}
+
+impl Uuid {
+    /// Write `uuids` as lowercase hyphenated ASCII strings into `buf`,
+    /// each followed by a newline, and return it as a string.
+    ///
+    /// This is equivalent to calling [`Uuid::to_str`] for every UUID,
+    /// but is faster for large numbers of UUIDs because many are hex encoded
+    /// at once.
+    ///
+    /// # Panics
+    ///
+    /// If `buf` is shorter than 37 bytes for every UUID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuids = [Uuid::nil(), Uuid::max()];
+    /// let mut buf = [0u8; 74];
+    /// assert_eq!(
+    ///     Uuid::to_str_batch(&uuids, &mut buf),
+    ///     "00000000-0000-0000-0000-000000000000\n\
+    ///      ffffffff-ffff-ffff-ffff-ffffffffffff\n"
+    /// );
+    /// ```
+    pub fn to_str_batch<'a>(uuids: &[Uuid], buf: &'a mut [u8]) -> &'a mut str {
+        let buf = &mut buf[..uuids.len() * LINE_LENGTH];
+        let mut hex = [0u8; CHUNK * 32];
+
+        for (uuids, buf) in uuids.chunks(CHUNK).zip(buf.chunks_mut(CHUNK * LINE_LENGTH)) {
+            let hex = &mut hex[..uuids.len() * 32];
+            let _ = hex_simd::encode(Uuid::slice_as_bytes(uuids), Out::from_slice(hex), Lower);
+
+            for (hex, line) in hex.chunks_exact(32).zip(buf.chunks_exact_mut(LINE_LENGTH)) {
+                line[..8].copy_from_slice(&hex[..8]);
+                line[8] = b'-';
+                line[9..13].copy_from_slice(&hex[8..12]);
+                line[13] = b'-';
+                line[14..18].copy_from_slice(&hex[12..16]);
+                line[18] = b'-';
+                line[19..23].copy_from_slice(&hex[16..20]);
+                line[23] = b'-';
+                line[24..36].copy_from_slice(&hex[20..]);
+                line[36] = b'\n';
+            }
+        }
+
+        debug_assert!(
+            buf.is_ascii(),
+            "BUG: Invalid ASCII in nuuid::Uuid::to_str_batch"
+        );
+        // Safety: Fully initialized with ASCII
+        unsafe { from_utf8_unchecked_mut(buf) }
+    }
+
+    /// [`Uuid::to_str_batch`], but returning a new `String`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let s = Uuid::to_string_batch(&[Uuid::nil(), Uuid::max()]);
+    /// assert_eq!(s.lines().count(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_string_batch(uuids: &[Uuid]) -> String {
+        let mut buf = vec![0; uuids.len() * LINE_LENGTH];
+        Uuid::to_str_batch(uuids, &mut buf);
+        // Safety: `to_str_batch` fully initializes `buf` with ASCII
+        unsafe { String::from_utf8_unchecked(buf) }
+    }
+
+    /// Parse newline separated UUIDs from `s` into `out`,
+    /// returning the number of UUIDs parsed.
+    ///
+    /// Each line is parsed as if by [`Uuid::parse`], and may end with `\r\n`.
+    /// A trailing newline is allowed.
+    /// Parsing stops once `out` is full, and any remaining lines are ignored.
+    ///
+    /// This is faster than calling [`Uuid::parse`] for every line,
+    /// because hyphenated UUIDs are hex decoded many at a time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let s = "00000000-0000-0000-0000-000000000000\n\
+    ///          ffffffff-ffff-ffff-ffff-ffffffffffff\n";
+    /// let mut uuids = [Uuid::nil(); 4];
+    /// let len = Uuid::parse_batch_into(s, &mut uuids).unwrap();
+    /// assert_eq!(uuids[..len], [Uuid::nil(), Uuid::max()]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If any line is not a valid UUID.
+    ///
+    /// The error is the same as [`Uuid::parse`] would return for that line,
+    /// except any byte index is relative to the start of `s`.
+    pub fn parse_batch_into(s: &str, out: &mut [Uuid]) -> Result<usize, ParseUuidError> {
+        let mut lines = s.split_terminator('\n');
+        // Start of the current line in `s`
+        let mut offset = 0;
+        let mut parsed = 0;
+        let mut hex = [0u8; CHUNK * 32];
+        // Start and length of each line in the chunk
+        let mut spans = [(0, 0); CHUNK];
+
+        for out in out.chunks_mut(CHUNK) {
+            let mut len = 0;
+            for line in lines.by_ref().take(out.len()) {
+                let start = offset;
+                offset += line.len() + 1;
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                let b = line.as_bytes();
+                let hex = &mut hex[len * 32..][..32];
+
+                if b.len() == UUID_STR_LENGTH && UUID_HYPHENS.iter().all(|&i| b[i] == b'-') {
+                    hex[..8].copy_from_slice(&b[..8]);
+                    hex[8..12].copy_from_slice(&b[9..13]);
+                    hex[12..16].copy_from_slice(&b[14..18]);
+                    hex[16..20].copy_from_slice(&b[19..23]);
+                    hex[20..].copy_from_slice(&b[24..]);
+                } else {
+                    // Other formats, and errors, are handled one at a time
+                    let uuid = parse_line(line, start)?;
+                    let _ = hex_simd::encode(&uuid.0, Out::from_slice(hex), Lower);
+                }
+                spans[len] = (start, line.len());
+                len += 1;
+            }
+            if len == 0 {
+                break;
+            }
+
+            let out = &mut out[..len];
+            let dst = Out::from_slice(Uuid::slice_as_bytes_mut(out));
+            if hex_simd::decode(&hex[..len * 32], dst).is_err() {
+                // Find the line responsible
+                for &(start, len) in &spans[..len] {
+                    parse_line(&s[start..start + len], start)?;
+                }
+                unreachable!("BUG: Invalid hex in nuuid::Uuid::parse_batch_into");
+            }
+            parsed += len;
+        }
+
+        Ok(parsed)
+    }
+
+    /// Parse newline separated UUIDs from `s`.
+    ///
+    /// See [`Uuid::parse_batch_into`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuids = Uuid::parse_batch(&Uuid::to_string_batch(&[Uuid::max(); 100])).unwrap();
+    /// assert_eq!(uuids, [Uuid::max(); 100]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If any line is not a valid UUID, see [`Uuid::parse_batch_into`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn parse_batch(s: &str) -> Result<Vec<Uuid>, ParseUuidError> {
+        let mut uuids = vec![Uuid::nil(); s.split_terminator('\n').count()];
+        let len = Uuid::parse_batch_into(s, &mut uuids)?;
+        debug_assert_eq!(len, uuids.len());
+        Ok(uuids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    #[test]
+    fn batch() {
+        let mut uuids = vec![Uuid::nil(); CHUNK * 3 + 5];
+        Uuid::fill_v4(&mut Rng::from_seed([0; 32]), &mut uuids);
+
+        let mut buf = vec![0; uuids.len() * 37];
+        let s = Uuid::to_str_batch(&uuids, &mut buf);
+        for (line, uuid) in s.lines().zip(&uuids) {
+            assert_eq!(line, uuid.to_str(&mut [0; 36]));
+        }
+        let mut parsed = vec![Uuid::nil(); uuids.len()];
+        assert_eq!(Uuid::parse_batch_into(s, &mut parsed).unwrap(), uuids.len());
+        assert_eq!(parsed, uuids);
+
+        let mut out = [Uuid::nil(); 2];
+        assert_eq!(Uuid::parse_batch_into(s, &mut out).unwrap(), 2);
+        assert_eq!(out, uuids[..2]);
+
+        #[cfg(feature = "std")]
+        {
+            let s = Uuid::to_string_batch(&uuids);
+            assert_eq!(s.as_bytes(), buf);
+            assert_eq!(Uuid::parse_batch(&s).unwrap(), uuids);
+        }
+
+        // Mixed formats and line endings
+        let s = "662aa7c7-7598-4d56-8bcc-a72c30f998a2\r\n\
+                 {662aa7c7-7598-4d56-8bcc-a72c30f998a2}\n\
+                 662AA7C775984D568BCCA72C30F998A2";
+        let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+        let mut out = [Uuid::nil(); 3];
+        assert_eq!(Uuid::parse_batch_into(s, &mut out).unwrap(), 3);
+        assert_eq!(out, [uuid; 3]);
+
+        let s = "662aa7c7-7598-4d56-8bcc-a72c30f998a2\n\
+                 662aa7c7-7598-4d56-8bcc-a72c30f998ag\n";
+        assert_eq!(
+            Uuid::parse_batch_into(s, &mut out).unwrap_err().kind(),
+            ParseErrorKind::InvalidCharacter {
+                found: 'g',
+                index: 37 + 35
+            }
+        );
+        let s = "662aa7c7-7598-4d56-8bcc-a72c30f998a2\n\n";
+        assert_eq!(
+            Uuid::parse_batch_into(s, &mut out).unwrap_err().kind(),
+            ParseErrorKind::InvalidLength { len: 0 }
+        );
+    }
+}
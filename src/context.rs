@@ -0,0 +1,343 @@
+//! Clock sequence state for time based UUIDs
+use rand_chacha::rand_core::RngCore;
+
+use crate::{Rng, Uuid};
+
+/// Mask for the 60-bit timestamp
+const TIMESTAMP_MASK: u64 = u64::MAX >> 4;
+
+/// Mask for the 14-bit clock sequence
+const CLOCK_SEQ_MASK: u16 = u16::MAX >> 2;
+
+/// Length of the [`ClockState`] binary representation
+const CLOCK_STATE_LENGTH: usize = 16;
+
+/// The state used by [`TimeContext`], as described in
+/// [RFC 4122 Section 4.2.1](https://www.rfc-editor.org/rfc/rfc4122#section-4.2.1)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ClockState {
+    /// The 60-bit timestamp of the last UUID
+    pub timestamp: u64,
+
+    /// The 14-bit clock sequence of the last UUID
+    pub clock_sequence: u16,
+
+    /// The node ID of the last UUID
+    pub node: [u8; 6],
+}
+
+impl ClockState {
+    /// Return the state as bytes, suitable for storage.
+    ///
+    /// The timestamp and clock sequence are stored big-endian,
+    /// followed by the node ID.
+    #[inline]
+    pub fn to_bytes(self) -> [u8; 16] {
+        let mut buf = [0; CLOCK_STATE_LENGTH];
+        buf[..8].copy_from_slice(&self.timestamp.to_be_bytes());
+        buf[8..10].copy_from_slice(&self.clock_sequence.to_be_bytes());
+        buf[10..].copy_from_slice(&self.node);
+        buf
+    }
+
+    /// Create the state from bytes created by [`ClockState::to_bytes`]
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        let [t0, t1, t2, t3, t4, t5, t6, t7, c0, c1, n0, n1, n2, n3, n4, n5] = bytes;
+        Self {
+            timestamp: u64::from_be_bytes([t0, t1, t2, t3, t4, t5, t6, t7]) & TIMESTAMP_MASK,
+            clock_sequence: u16::from_be_bytes([c0, c1]) & CLOCK_SEQ_MASK,
+            node: [n0, n1, n2, n3, n4, n5],
+        }
+    }
+}
+
+/// Stable storage for a [`ClockState`]
+///
+/// Used with [`TimeContext::restore`] and [`TimeContext::save`] so the
+/// clock sequence survives process restarts.
+pub trait ClockStore {
+    /// Error returned when the state can't be loaded or saved.
+    type Error;
+
+    /// Load the saved state, returning `None` if there isn't any.
+    fn load(&mut self) -> Result<Option<ClockState>, Self::Error>;
+
+    /// Save the state, replacing any previously saved state.
+    fn save(&mut self, state: &ClockState) -> Result<(), Self::Error>;
+}
+
+/// A [`ClockStore`] backed by a file
+///
+/// The file contains the [`ClockState::to_bytes`] representation,
+/// and is created when first saved.
+///
+/// Saving writes to a temporary file in the same directory and renames it
+/// over the original, so the file is never left partially written.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # fn main() -> std::io::Result<()> {
+/// # use nuuid::{FileClockStore, Rng, TimeContext};
+/// # let (TIMESTAMP, NODE) = (138788330336896890, *b"world!");
+/// let mut store = FileClockStore::new("/var/lib/example/clock");
+/// let mut ctx = TimeContext::restore(Rng::new(), &mut store)?;
+/// let uuid = ctx.new_v1(TIMESTAMP, NODE);
+/// ctx.save(&mut store)?;
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone)]
+pub struct FileClockStore {
+    path: std::path::PathBuf,
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl FileClockStore {
+    /// Create a new store using the file at `path`
+    #[inline]
+    pub fn new<P: Into<std::path::PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// The path to the file
+    #[inline]
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl ClockStore for FileClockStore {
+    type Error = std::io::Error;
+
+    fn load(&mut self) -> Result<Option<ClockState>, Self::Error> {
+        use std::io::{Error, ErrorKind};
+
+        let data = match std::fs::read(&self.path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let bytes = data
+            .try_into()
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "Invalid clock state"))?;
+        Ok(Some(ClockState::from_bytes(bytes)))
+    }
+
+    fn save(&mut self, state: &ClockState) -> Result<(), Self::Error> {
+        use std::{
+            fs::{self, File},
+            io::Write,
+            sync::atomic::{AtomicUsize, Ordering},
+        };
+
+        // Unique per process and save, so concurrent writers don't collide
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(format!(
+            ".{}.{}.tmp",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let write = || {
+            let mut file = File::create(&tmp)?;
+            file.write_all(&state.to_bytes())?;
+            file.sync_all()?;
+            fs::rename(&tmp, &self.path)
+        };
+        write().inspect_err(|_| {
+            let _ = fs::remove_file(&tmp);
+        })
+    }
+}
+
+/// Clock sequence context for Version 1 and 6 UUIDs
+///
+/// Unlike [`Uuid::new_v1`], this keeps track of the last timestamp and clock
+/// sequence, as described in
+/// [RFC 4122 Section 4.2.1](https://www.rfc-editor.org/rfc/rfc4122#section-4.2.1).
+///
+/// - If there is no previous state, or the node ID changed,
+///   the clock sequence is randomized.
+/// - If the timestamp did not move forward, either because of a duplicate
+///   timestamp or the clock moving backwards, the clock sequence is incremented.
+///
+/// Random values come from `R`, which can be any [`RngCore`],
+/// and defaults to [`Rng`].
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{Rng, TimeContext};
+/// # let (seed, TIMESTAMP, NODE) = ([0; 32], 138788330336896890, *b"world!");
+/// let mut ctx = TimeContext::new(Rng::from_seed(seed));
+/// let first = ctx.new_v1(TIMESTAMP, NODE);
+/// let second = ctx.new_v1(TIMESTAMP, NODE);
+/// assert_ne!(first, second);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TimeContext<R = Rng> {
+    rng: R,
+    state: Option<ClockState>,
+}
+
+impl<R: RngCore> TimeContext<R> {
+    /// Create a new context with no previous state
+    #[inline]
+    pub fn new(rng: R) -> Self {
+        Self { rng, state: None }
+    }
+
+    /// Create a new context using the state loaded from `store`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{ClockState, ClockStore, Rng, TimeContext};
+    /// # let (seed, TIMESTAMP, NODE) = ([0; 32], 138788330336896890, *b"world!");
+    /// /// Keeps the state in memory, such as in battery backed RAM
+    /// struct MemoryStore(Option<ClockState>);
+    ///
+    /// impl ClockStore for MemoryStore {
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn load(&mut self) -> Result<Option<ClockState>, Self::Error> {
+    ///         Ok(self.0)
+    ///     }
+    ///
+    ///     fn save(&mut self, state: &ClockState) -> Result<(), Self::Error> {
+    ///         self.0 = Some(*state);
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut store = MemoryStore(None);
+    /// let mut ctx = TimeContext::restore(Rng::from_seed(seed), &mut store).unwrap();
+    /// let uuid = ctx.new_v1(TIMESTAMP, NODE);
+    /// ctx.save(&mut store).unwrap();
+    /// assert_eq!(store.0, ctx.state());
+    /// ```
+    #[inline]
+    pub fn restore<S: ClockStore>(rng: R, store: &mut S) -> Result<Self, S::Error> {
+        Ok(Self {
+            rng,
+            state: store.load()?,
+        })
+    }
+
+    /// Save the current state to `store`
+    ///
+    /// Does nothing if no UUIDs have been created or restored yet.
+    #[inline]
+    pub fn save<S: ClockStore>(&self, store: &mut S) -> Result<(), S::Error> {
+        match &self.state {
+            Some(state) => store.save(state),
+            None => Ok(()),
+        }
+    }
+
+    /// The state of the last UUID, if any
+    #[inline]
+    pub fn state(&self) -> Option<ClockState> {
+        self.state
+    }
+
+    /// Update the state for a new UUID, returning the clock sequence to use.
+    fn next(&mut self, timestamp: u64, node: [u8; 6]) -> u16 {
+        let timestamp = timestamp & TIMESTAMP_MASK;
+        let clock_sequence = match self.state {
+            Some(s) if s.node == node && timestamp <= s.timestamp => {
+                s.clock_sequence.wrapping_add(1) & CLOCK_SEQ_MASK
+            }
+            Some(s) if s.node == node => s.clock_sequence,
+            _ => self.rng.next_u32() as u16 & CLOCK_SEQ_MASK,
+        };
+        self.state = Some(ClockState {
+            timestamp,
+            clock_sequence,
+            node,
+        });
+        clock_sequence
+    }
+
+    /// Create a new Version 1 UUID using the provided 60-bit timestamp and
+    /// node, and the clock sequence from this context.
+    ///
+    /// See [`Uuid::new_v1`] for details.
+    #[inline]
+    pub fn new_v1(&mut self, timestamp: u64, node: [u8; 6]) -> Uuid {
+        let counter = self.next(timestamp, node);
+        Uuid::new_v1(timestamp, counter, node)
+    }
+
+    /// Create a new Version 6 UUID using the provided 60-bit timestamp and
+    /// node, and the clock sequence from this context.
+    ///
+    /// See [`Uuid::new_v6`] for details.
+    #[inline]
+    pub fn new_v6(&mut self, timestamp: u64, node: [u8; 6]) -> Uuid {
+        let counter = self.next(timestamp, node);
+        Uuid::new_v6(timestamp, counter, node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_sequence() {
+        let (ticks, node) = (138788330336896890u64, *b"world!");
+        let mut ctx = TimeContext::new(Rng::from_seed([0; 32]));
+
+        let first = ctx.new_v1(ticks, node);
+        let seq = first.clock_sequence();
+
+        let uuid = ctx.new_v1(ticks + 1, node);
+        assert_eq!(uuid.clock_sequence(), seq, "Clock moved forward");
+
+        let uuid = ctx.new_v1(ticks + 1, node);
+        assert_eq!(uuid.clock_sequence(), seq + 1, "Duplicate timestamp");
+
+        let uuid = ctx.new_v1(ticks, node);
+        assert_eq!(uuid.clock_sequence(), seq + 2, "Clock moved backwards");
+
+        let state = ctx.state().unwrap();
+        assert_eq!(ClockState::from_bytes(state.to_bytes()), state);
+
+        #[cfg(feature = "std")]
+        {
+            let dir = std::env::temp_dir().join(format!("nuuid-test-{}", std::process::id()));
+            std::fs::create_dir_all(&dir).unwrap();
+            let mut store = FileClockStore::new(dir.join("clock"));
+
+            let restored = TimeContext::restore(Rng::from_seed([1; 32]), &mut store).unwrap();
+            assert_eq!(restored.state(), None, "Missing file");
+
+            ctx.save(&mut store).unwrap();
+            let mut restored = TimeContext::restore(Rng::from_seed([1; 32]), &mut store).unwrap();
+            assert_eq!(restored.state(), Some(state));
+            let uuid = restored.new_v1(ticks, node);
+            assert_eq!(uuid.clock_sequence(), seq + 3, "Restored clock sequence");
+
+            // Saving again replaces the file, leaving nothing else behind
+            restored.save(&mut store).unwrap();
+            assert_eq!(store.load().unwrap(), restored.state());
+            assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+            std::fs::write(store.path(), b"torn").unwrap();
+            assert_eq!(
+                store.load().unwrap_err().kind(),
+                std::io::ErrorKind::InvalidData
+            );
+
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+}
@@ -0,0 +1,333 @@
+//! Compact text encodings
+use core::str::from_utf8_unchecked_mut;
+
+use crate::{ParseErrorKind, ParseUuidError, Uuid};
+
+/// Crockford's Base32 alphabet
+const BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The Bitcoin Base58 alphabet
+const BASE58: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The URL and filename safe Base64 alphabet
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Marks bytes that are not in an alphabet
+const INVALID: u8 = 0xFF;
+
+/// Map each byte to its value in `alphabet`, or [`INVALID`].
+const fn decode_table(alphabet: &[u8]) -> [u8; 256] {
+    let mut table = [INVALID; 256];
+    let mut i = 0;
+    while i < alphabet.len() {
+        table[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+/// Crockford's Base32 is case insensitive, and maps the easily confused
+/// `I`, `L`, and `O` to `1` and `0`.
+const BASE32_DECODE: [u8; 256] = {
+    let mut table = decode_table(BASE32);
+    let mut i = 0;
+    while i < BASE32.len() {
+        table[BASE32[i].to_ascii_lowercase() as usize] = i as u8;
+        i += 1;
+    }
+    table[b'I' as usize] = 1;
+    table[b'i' as usize] = 1;
+    table[b'L' as usize] = 1;
+    table[b'l' as usize] = 1;
+    table[b'O' as usize] = 0;
+    table[b'o' as usize] = 0;
+    table
+};
+
+const BASE58_DECODE: [u8; 256] = decode_table(BASE58);
+
+const BASE64URL_DECODE: [u8; 256] = decode_table(BASE64URL);
+
+/// Check `s` is `expected` bytes long
+#[inline]
+fn check_length(s: &str, expected: usize) -> Result<(), ParseUuidError> {
+    match s.len() == expected {
+        true => Ok(()),
+        false => Err(ParseUuidError::new(ParseErrorKind::InvalidEncodedLength {
+            len: s.len(),
+            expected,
+        })),
+    }
+}
+
+/// Decode the digit at `s[index]` using `table`
+#[inline]
+fn digit(s: &str, index: usize, table: &[u8; 256]) -> Result<u8, ParseUuidError> {
+    match table[s.as_bytes()[index] as usize] {
+        INVALID => Err(invalid_digit(s, index)),
+        d => Ok(d),
+    }
+}
+
+#[cold]
+fn invalid_digit(s: &str, index: usize) -> ParseUuidError {
+    // `index` may be in the middle of a multi-byte character
+    let start = (0..=index)
+        .rev()
+        .find(|i| s.is_char_boundary(*i))
+        .unwrap_or(0);
+    let found = s[start..].chars().next().unwrap_or_default();
+    ParseUuidError::new(ParseErrorKind::InvalidDigit {
+        found,
+        index: start,
+    })
+}
+
+impl Uuid {
+    /// Write UUID as an uppercase Crockford Base32 string into `buf`,
+    /// and return it as a string.
+    ///
+    /// This is the encoding used by ULIDs, and sorts the same as the UUID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap();
+    /// assert_eq!(uuid.to_base32(&mut [0; 26]), "01FWHE4YDGFK1SHH6W1G60EECF");
+    /// ```
+    pub fn to_base32(self, buf: &mut [u8; 26]) -> &mut str {
+        let value = u128::from_be_bytes(self.to_bytes());
+        for (i, b) in buf.iter_mut().enumerate() {
+            // The first digit only holds the highest 3 bits
+            let shift = 125 - 5 * i as u32;
+            *b = BASE32[(value >> shift) as usize & 0x1F];
+        }
+        // Safety: Fully initialized with ASCII
+        unsafe { from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Parse a [`Uuid`] from a Crockford Base32 string,
+    /// as created by [`Uuid::to_base32`].
+    ///
+    /// This method is case insensitive, and accepts `I` and `L` as `1`,
+    /// and `O` as `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse_base32("01FWHE4YDGFK1SHH6W1G60EECF").unwrap();
+    /// assert_eq!(uuid, Uuid::parse("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `s` is not 26 valid Base32 digits, or the first digit is larger
+    /// than `7`, which would not fit in 128 bits.
+    pub fn parse_base32(s: &str) -> Result<Self, ParseUuidError> {
+        check_length(s, 26)?;
+        if digit(s, 0, &BASE32_DECODE)? > 7 {
+            return Err(ParseUuidError::new(ParseErrorKind::Overflow));
+        }
+        let mut value = 0u128;
+        for i in 0..26 {
+            value = (value << 5) | digit(s, i, &BASE32_DECODE)? as u128;
+        }
+        Ok(Uuid::from_bytes(value.to_be_bytes()))
+    }
+
+    /// Write UUID as a Base58 string into `buf`, using the Bitcoin alphabet,
+    /// and return it as a string.
+    ///
+    /// The string is always 22 characters, padded with leading `1`s,
+    /// the Base58 zero digit, and sorts the same as the UUID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap();
+    /// assert_eq!(uuid.to_base58(&mut [0; 22]), "1BihbxwwQ4NZZpKRH9JDCz");
+    /// ```
+    pub fn to_base58(self, buf: &mut [u8; 22]) -> &mut str {
+        let mut value = u128::from_be_bytes(self.to_bytes());
+        for b in buf.iter_mut().rev() {
+            *b = BASE58[(value % 58) as usize];
+            value /= 58;
+        }
+        // Safety: Fully initialized with ASCII
+        unsafe { from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Parse a [`Uuid`] from a 22 character Base58 string using the Bitcoin
+    /// alphabet, as created by [`Uuid::to_base58`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse_base58("1BihbxwwQ4NZZpKRH9JDCz").unwrap();
+    /// assert_eq!(uuid, Uuid::parse("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `s` is not 22 valid Base58 digits, or the value would not fit in
+    /// 128 bits.
+    pub fn parse_base58(s: &str) -> Result<Self, ParseUuidError> {
+        check_length(s, 22)?;
+        let mut value = 0u128;
+        for i in 0..22 {
+            let d = digit(s, i, &BASE58_DECODE)?;
+            value = value
+                .checked_mul(58)
+                .and_then(|v| v.checked_add(d as u128))
+                .ok_or_else(|| ParseUuidError::new(ParseErrorKind::Overflow))?;
+        }
+        Ok(Uuid::from_bytes(value.to_be_bytes()))
+    }
+
+    /// Write UUID as an unpadded URL safe Base64 string into `buf`,
+    /// and return it as a string.
+    ///
+    /// Unlike [`Uuid::to_base32`] and [`Uuid::to_base58`], this does not sort
+    /// the same as the UUID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap();
+    /// assert_eq!(uuid.to_base64url(&mut [0; 22]), "AX8i4nmwfMOYxNwMDAc5jw");
+    /// ```
+    pub fn to_base64url(self, buf: &mut [u8; 22]) -> &mut str {
+        let value = u128::from_be_bytes(self.to_bytes());
+        for (i, b) in buf[..21].iter_mut().enumerate() {
+            let shift = 122 - 6 * i as u32;
+            *b = BASE64URL[(value >> shift) as usize & 0x3F];
+        }
+        // The last digit holds the lowest 2 bits, followed by 4 zero bits
+        buf[21] = BASE64URL[(value as usize & 0x3) << 4];
+        // Safety: Fully initialized with ASCII
+        unsafe { from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Parse a [`Uuid`] from an unpadded URL safe Base64 string,
+    /// as created by [`Uuid::to_base64url`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse_base64url("AX8i4nmwfMOYxNwMDAc5jw").unwrap();
+    /// assert_eq!(uuid, Uuid::parse("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `s` is not 22 valid Base64 digits, or the unused low bits of the
+    /// last digit are not zero.
+    pub fn parse_base64url(s: &str) -> Result<Self, ParseUuidError> {
+        check_length(s, 22)?;
+        let mut value = 0u128;
+        for i in 0..21 {
+            value = (value << 6) | digit(s, i, &BASE64URL_DECODE)? as u128;
+        }
+        let last = digit(s, 21, &BASE64URL_DECODE)?;
+        if last & 0xF != 0 {
+            return Err(invalid_digit(s, 21));
+        }
+        value = (value << 2) | (last >> 4) as u128;
+        Ok(Uuid::from_bytes(value.to_be_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodings() {
+        for uuid in [Uuid::nil(), Uuid::max(), Uuid::new_v4()] {
+            assert_eq!(Uuid::parse_base32(uuid.to_base32(&mut [0; 26])), Ok(uuid));
+            assert_eq!(Uuid::parse_base58(uuid.to_base58(&mut [0; 22])), Ok(uuid));
+            assert_eq!(
+                Uuid::parse_base64url(uuid.to_base64url(&mut [0; 22])),
+                Ok(uuid)
+            );
+        }
+        assert_eq!(
+            Uuid::nil().to_base58(&mut [0; 22]),
+            "1111111111111111111111"
+        );
+        assert_eq!(
+            Uuid::max().to_base32(&mut [0; 26]),
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+        );
+        assert_eq!(
+            Uuid::max().to_base58(&mut [0; 22]),
+            "YcVfxkQb6JRzqk5kF2tNLv"
+        );
+
+        // Order is preserved
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        assert_eq!(
+            a.cmp(&b),
+            a.to_base32(&mut [0; 26]).cmp(&b.to_base32(&mut [0; 26]))
+        );
+        assert_eq!(
+            a.cmp(&b),
+            a.to_base58(&mut [0; 22]).cmp(&b.to_base58(&mut [0; 22]))
+        );
+
+        let kind = |r: Result<Uuid, ParseUuidError>| r.unwrap_err().kind();
+        assert_eq!(
+            Uuid::parse_base32("01fwhe4ydgfkisHH6W1G60EECF"),
+            Uuid::parse_base32("01FWHE4YDGFK1SHH6W1G60EECF")
+        );
+        assert_eq!(
+            kind(Uuid::parse_base32("8ZZZZZZZZZZZZZZZZZZZZZZZZZ")),
+            ParseErrorKind::Overflow
+        );
+        assert_eq!(
+            kind(Uuid::parse_base32("01FWHE4YDGFK1SHH6W1G60EECU")),
+            ParseErrorKind::InvalidDigit {
+                found: 'U',
+                index: 25
+            }
+        );
+        assert_eq!(
+            kind(Uuid::parse_base58("YcVfxkQb6JRzqk5kF2tNLw")),
+            ParseErrorKind::Overflow
+        );
+        assert_eq!(
+            kind(Uuid::parse_base58("1BKocRBNaGdMEnnppZx30M")),
+            ParseErrorKind::InvalidDigit {
+                found: '0',
+                index: 20
+            }
+        );
+        assert_eq!(
+            kind(Uuid::parse_base58("1BKocRBNaGdMEnnppZx3é")),
+            ParseErrorKind::InvalidDigit {
+                found: 'é',
+                index: 20
+            }
+        );
+        assert_eq!(
+            kind(Uuid::parse_base64url("AX8i4nmwfMOYxNwMDAc5jx")),
+            ParseErrorKind::InvalidDigit {
+                found: 'x',
+                index: 21
+            }
+        );
+        assert_eq!(
+            kind(Uuid::parse_base64url("AX8i4nmwfMOYxNwMDAc5j")),
+            ParseErrorKind::InvalidEncodedLength {
+                len: 21,
+                expected: 22
+            }
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Stateful UUID generators
+use rand_chacha::rand_core::RngCore;
+
+use crate::{Rng, Uuid};
+
+/// Largest value of the 12-bit `rand_a` field.
+const RAND_A_MAX: u16 = 0xFFF;
+
+/// Mask for the 62 usable bits of the `rand_b` field.
+const RAND_B_MASK: u64 = u64::MAX >> 2;
+
+/// Largest value of the combined 74-bit `rand_a` and `rand_b` fields.
+const RAND_MAX: u128 = (1 << 74) - 1;
+
+/// Number of nanoseconds in a millisecond.
+const NANOS_PER_MILLI: u32 = 1_000_000;
+
+/// Method used by [`V7Generator`] to keep UUIDs created within the same
+/// millisecond in creation order.
+///
+/// See [RFC 9562 Section 6.2][rfc] for details on each method.
+///
+/// [rfc]: https://www.rfc-editor.org/rfc/rfc9562#section-6.2
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum Monotonicity {
+    /// Method 1, a 12-bit counter stored in `rand_a`.
+    ///
+    /// The counter is randomly seeded each millisecond, with its highest bit
+    /// cleared to guard against rollover, and incremented for each UUID in
+    /// the same millisecond. `rand_b` is always random.
+    #[default]
+    Counter,
+
+    /// Method 2, monotonic random.
+    ///
+    /// `rand_a` and `rand_b` are treated as one 74-bit random value, which is
+    /// incremented by a random amount for each UUID in the same millisecond.
+    MonotonicRandom,
+
+    /// Method 3, increased clock precision.
+    ///
+    /// `rand_a` holds the sub-millisecond fraction of the timestamp,
+    /// see [`V7Generator::generate_precise`]. `rand_b` is always random.
+    SubMillisecond,
+}
+
+/// A stateful generator for Version 7 UUIDs
+///
+/// Unlike [`Uuid::new_v7`], every UUID created by the same generator is
+/// guaranteed to sort after the previous one, even when created in the
+/// same millisecond.
+///
+/// If the clock moves backwards, or a millisecond runs out of values,
+/// the generator keeps using, and then advances, the last timestamp it used,
+/// so the resulting timestamp may briefly be ahead of the real time.
+///
+/// Random values come from `R`, which can be any [`RngCore`],
+/// and defaults to [`Rng`].
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{Rng, V7Generator};
+/// # let (seed, TIMESTAMP) = ([0; 32], 0x17F22E279B0);
+/// let mut gen = V7Generator::new(Rng::from_seed(seed));
+/// let first = gen.generate(TIMESTAMP);
+/// let second = gen.generate(TIMESTAMP);
+/// assert!(first < second);
+/// ```
+#[derive(Debug, Clone)]
+pub struct V7Generator<R = Rng> {
+    rng: R,
+    method: Monotonicity,
+
+    /// Timestamp of the last UUID
+    unix_ts_ms: u64,
+
+    /// `rand_a` of the last UUID
+    rand_a: u16,
+
+    /// `rand_b` of the last UUID
+    rand_b: u64,
+}
+
+impl<R: RngCore> V7Generator<R> {
+    /// Create a new generator using [`Monotonicity::Counter`]
+    #[inline]
+    pub fn new(rng: R) -> Self {
+        Self::with_method(rng, Monotonicity::Counter)
+    }
+
+    /// Create a new generator using the provided [`Monotonicity`] method
+    #[inline]
+    pub fn with_method(rng: R, method: Monotonicity) -> Self {
+        Self {
+            rng,
+            method,
+            unix_ts_ms: 0,
+            rand_a: 0,
+            rand_b: 0,
+        }
+    }
+
+    /// The [`Monotonicity`] method used by this generator
+    #[inline]
+    pub fn method(&self) -> Monotonicity {
+        self.method
+    }
+
+    /// Create a new Version 7 UUID using the provided 48-bit millisecond
+    /// UNIX timestamp.
+    ///
+    /// The 16 high bits of `unix_ts_ms` are ignored
+    ///
+    /// The result is guaranteed to sort after every UUID previously
+    /// created by this generator.
+    #[inline]
+    pub fn generate(&mut self, unix_ts_ms: u64) -> Uuid {
+        self.generate_precise(unix_ts_ms, 0)
+    }
+
+    /// [`V7Generator::generate`], but with an additional `nanos` sub-millisecond
+    /// timestamp.
+    ///
+    /// `nanos` is only used by [`Monotonicity::SubMillisecond`], and is
+    /// clamped to `999_999`.
+    pub fn generate_precise(&mut self, unix_ts_ms: u64, nanos: u32) -> Uuid {
+        let unix_ts_ms = unix_ts_ms & 0xFFFF_FFFF_FFFF;
+        // Clock went backwards, or didn't move. Keep the last timestamp.
+        let same = unix_ts_ms <= self.unix_ts_ms;
+        let unix_ts_ms = if same { self.unix_ts_ms } else { unix_ts_ms };
+
+        match self.method {
+            Monotonicity::Counter => {
+                if same && self.rand_a < RAND_A_MAX {
+                    self.rand_a += 1;
+                } else if same {
+                    self.advance(unix_ts_ms);
+                    self.rand_a = self.counter_seed();
+                } else {
+                    self.unix_ts_ms = unix_ts_ms;
+                    self.rand_a = self.counter_seed();
+                }
+                self.rand_b = self.rng.next_u64() & RAND_B_MASK;
+            }
+            Monotonicity::MonotonicRandom => {
+                let last = ((self.rand_a as u128) << 62) | self.rand_b as u128;
+                let step = self.rng.next_u32() as u128 + 1;
+                if same && last + step <= RAND_MAX {
+                    let next = last + step;
+                    self.rand_a = (next >> 62) as u16;
+                    self.rand_b = next as u64 & RAND_B_MASK;
+                } else {
+                    if same {
+                        self.advance(unix_ts_ms);
+                    } else {
+                        self.unix_ts_ms = unix_ts_ms;
+                    }
+                    self.rand_a = self.rng.next_u32() as u16 & RAND_A_MAX;
+                    self.rand_b = self.rng.next_u64() & RAND_B_MASK;
+                }
+            }
+            Monotonicity::SubMillisecond => {
+                let nanos = nanos.min(NANOS_PER_MILLI - 1);
+                // Scale to 12 bits, as described in RFC 9562 Section 6.2 Method 3
+                let frac = (nanos as u64 * (RAND_A_MAX as u64 + 1) / NANOS_PER_MILLI as u64) as u16;
+                if !same || frac > self.rand_a {
+                    self.unix_ts_ms = unix_ts_ms;
+                    self.rand_a = frac;
+                } else if self.rand_a < RAND_A_MAX {
+                    self.rand_a += 1;
+                } else {
+                    self.advance(unix_ts_ms);
+                    self.rand_a = 0;
+                }
+                self.rand_b = self.rng.next_u64() & RAND_B_MASK;
+            }
+        }
+
+        Uuid::new_v7(self.unix_ts_ms, self.rand_a, self.rand_b)
+    }
+
+    /// Move the timestamp ahead by one millisecond, when the last one
+    /// ran out of values.
+    #[inline]
+    fn advance(&mut self, unix_ts_ms: u64) {
+        self.unix_ts_ms = (unix_ts_ms + 1) & 0xFFFF_FFFF_FFFF;
+    }
+
+    /// A random counter value, with the highest bit cleared.
+    #[inline]
+    fn counter_seed(&mut self) -> u16 {
+        self.rng.next_u32() as u16 & (RAND_A_MAX >> 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_chacha::rand_core::{impls, Error};
+
+    use super::*;
+    use crate::{Variant, Version};
+
+    /// Deterministic counting RNG
+    struct Counter(u64);
+
+    impl RngCore for Counter {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 += 1;
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    fn ordered(method: Monotonicity) {
+        let mut gen = V7Generator::with_method(Rng::from_seed([7; 32]), method);
+        let mut last = gen.generate(0x17F22E279B0);
+        // Same millisecond, enough to overflow any 12-bit counter
+        for i in 0..10_000 {
+            let uuid = gen.generate_precise(0x17F22E279B0, i);
+            assert!(uuid > last, "{:?} UUIDs must be in creation order", method);
+            assert_eq!(uuid.version(), Version::UnixTime);
+            assert_eq!(uuid.variant(), Variant::Rfc4122);
+            last = uuid;
+        }
+
+        // Clock moved backwards
+        let uuid = gen.generate(0x17F22E279B0 - 1000);
+        assert!(
+            uuid > last,
+            "{:?} UUIDs must survive clock regression",
+            method
+        );
+        last = uuid;
+
+        // Clock moved forwards, well past any overflow
+        let uuid = gen.generate(0x17F22E279B0 + 1000);
+        assert!(uuid > last);
+        assert_eq!(
+            uuid.to_bytes()[..6],
+            (0x17F22E279B0u64 + 1000).to_be_bytes()[2..]
+        );
+    }
+
+    #[test]
+    fn monotonic() {
+        ordered(Monotonicity::Counter);
+        ordered(Monotonicity::MonotonicRandom);
+        ordered(Monotonicity::SubMillisecond);
+    }
+
+    #[test]
+    fn custom_rng() {
+        let mut gen = V7Generator::new(Counter(0));
+        let first = gen.generate(0x17F22E279B0);
+        let second = gen.generate(0x17F22E279B0);
+        assert!(first < second);
+        // Seeded from the first value, incremented for the second
+        assert_eq!(first.to_bytes()[6..8], [0x70, 0x01]);
+        assert_eq!(second.to_bytes()[6..8], [0x70, 0x02]);
+
+        let mut rng = Counter(0);
+        assert_eq!(
+            Uuid::new_v4_rng(&mut rng),
+            Uuid::new_v4_rng(&mut Counter(0))
+        );
+        assert_ne!(
+            Uuid::new_v4_rng(&mut rng),
+            Uuid::new_v4_rng(&mut Counter(0))
+        );
+    }
+}
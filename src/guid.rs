@@ -0,0 +1,236 @@
+//! Microsoft GUID structure notation
+use core::str::from_utf8_unchecked_mut;
+
+use hex_simd::{AsciiCase::Lower, Out};
+
+use crate::{ParseErrorKind, ParseUuidError, Uuid};
+
+/// Parser state for [`Uuid::parse_struct`]
+struct StructParser<'a> {
+    s: &'a [u8],
+    index: usize,
+}
+
+impl StructParser<'_> {
+    #[inline]
+    fn error(&self) -> ParseUuidError {
+        ParseUuidError::new(ParseErrorKind::InvalidStructSyntax { index: self.index })
+    }
+
+    /// Skip any whitespace
+    #[inline]
+    fn skip_whitespace(&mut self) {
+        while matches!(self.s.get(self.index), Some(b) if b.is_ascii_whitespace()) {
+            self.index += 1;
+        }
+    }
+
+    /// Expect `byte`, after any whitespace
+    fn expect(&mut self, byte: u8) -> Result<(), ParseUuidError> {
+        self.skip_whitespace();
+        match self.s.get(self.index) {
+            Some(b) if *b == byte => {
+                self.index += 1;
+                Ok(())
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    /// Expect a `0x` prefixed hex number of up to `digits` digits,
+    /// after any whitespace
+    fn hex(&mut self, digits: usize) -> Result<u32, ParseUuidError> {
+        self.skip_whitespace();
+        match self.s.get(self.index..self.index + 2) {
+            Some([b'0', b'x' | b'X']) => self.index += 2,
+            _ => return Err(self.error()),
+        }
+
+        let start = self.index;
+        let mut value = 0;
+        while let Some(b) = self.s.get(self.index) {
+            let digit = match (*b as char).to_digit(16) {
+                Some(digit) => digit,
+                None => break,
+            };
+            if self.index - start == digits {
+                return Err(self.error());
+            }
+            value = (value << 4) | digit;
+            self.index += 1;
+        }
+        if self.index == start {
+            return Err(self.error());
+        }
+        Ok(value)
+    }
+
+    /// Expect the end of input, after any whitespace
+    fn end(&mut self) -> Result<(), ParseUuidError> {
+        self.skip_whitespace();
+        match self.index == self.s.len() {
+            true => Ok(()),
+            false => Err(self.error()),
+        }
+    }
+}
+
+impl Uuid {
+    /// Parse a [`Uuid`] from the Microsoft GUID structure notation
+    ///
+    /// This is the C structure initializer format used by `DEFINE_GUID` in
+    /// the Windows SDK headers, such as
+    /// `{0x662aa7c7,0x7598,0x4d56,{0x8b,0xcc,0xa7,0x2c,0x30,0xf9,0x98,0xa2}}`.
+    ///
+    /// This method is case insensitive, allows whitespace between elements,
+    /// and allows leading zeros to be omitted.
+    ///
+    /// Registry GUIDs, such as those from `.reg` exports, use the braced
+    /// format supported by [`Uuid::parse`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse_struct(
+    ///     "{0x662aa7c7,0x7598,0x4d56,{0x8b,0xcc,0xa7,0x2c,0x30,0xf9,0x98,0xa2}}",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(uuid, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+    ///
+    /// Uuid::parse_struct(
+    ///     "{ 0x662AA7C7, 0x7598, 0x4D56, { 0x8B, 0xCC, 0xA7, 0x2C, 0x30, 0xF9, 0x98, 0xA2 } }",
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn parse_struct(s: &str) -> Result<Self, ParseUuidError> {
+        let mut p = StructParser {
+            s: s.as_bytes(),
+            index: 0,
+        };
+        let mut bytes = [0; 16];
+
+        p.expect(b'{')?;
+        bytes[..4].copy_from_slice(&p.hex(8)?.to_be_bytes());
+        p.expect(b',')?;
+        bytes[4..6].copy_from_slice(&(p.hex(4)? as u16).to_be_bytes());
+        p.expect(b',')?;
+        bytes[6..8].copy_from_slice(&(p.hex(4)? as u16).to_be_bytes());
+        p.expect(b',')?;
+
+        p.expect(b'{')?;
+        for (i, byte) in bytes[8..].iter_mut().enumerate() {
+            if i != 0 {
+                p.expect(b',')?;
+            }
+            *byte = p.hex(2)? as u8;
+        }
+        p.expect(b'}')?;
+        p.expect(b'}')?;
+        p.end()?;
+
+        Ok(Uuid::from_bytes(bytes))
+    }
+
+    /// Write UUID as a lowercase ASCII Microsoft GUID structure into `buf`,
+    /// and return it as a string.
+    ///
+    /// See [`Uuid::parse_struct`] for details on the format.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// let mut buf = [0u8; 68];
+    /// assert_eq!(
+    ///     uuid.to_struct(&mut buf),
+    ///     "{0x662aa7c7,0x7598,0x4d56,{0x8b,0xcc,0xa7,0x2c,0x30,0xf9,0x98,0xa2}}"
+    /// );
+    /// ```
+    pub fn to_struct(self, buf: &mut [u8; 68]) -> &mut str {
+        let bytes = self.to_bytes();
+
+        buf[..3].copy_from_slice(b"{0x");
+        let _ = hex_simd::encode(&bytes[..4], Out::from_slice(&mut buf[3..11]), Lower);
+        buf[11..14].copy_from_slice(b",0x");
+        let _ = hex_simd::encode(&bytes[4..6], Out::from_slice(&mut buf[14..18]), Lower);
+        buf[18..21].copy_from_slice(b",0x");
+        let _ = hex_simd::encode(&bytes[6..8], Out::from_slice(&mut buf[21..25]), Lower);
+        buf[25] = b',';
+
+        // Each byte of the last field is `{0x00` or `,0x00`
+        for (i, byte) in bytes[8..].iter().enumerate() {
+            let start = 26 + i * 5;
+            buf[start..start + 3].copy_from_slice(if i == 0 { b"{0x" } else { b",0x" });
+            let _ = hex_simd::encode(
+                core::slice::from_ref(byte),
+                Out::from_slice(&mut buf[start + 3..start + 5]),
+                Lower,
+            );
+        }
+        buf[66..].copy_from_slice(b"}}");
+
+        debug_assert!(
+            buf.is_ascii(),
+            "BUG: Invalid ASCII in nuuid::Uuid::to_struct"
+        );
+        // Safety: Fully initialized with ASCII
+        unsafe { from_utf8_unchecked_mut(buf) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UUID_V4: &str = "662aa7c7-7598-4d56-8bcc-a72c30f998a2";
+    const UUID_V4_STRUCT: &str =
+        "{0x662aa7c7,0x7598,0x4d56,{0x8b,0xcc,0xa7,0x2c,0x30,0xf9,0x98,0xa2}}";
+
+    #[test]
+    fn guid_struct() {
+        let uuid = Uuid::parse(UUID_V4).unwrap();
+        assert_eq!(uuid.to_struct(&mut [0; 68]), UUID_V4_STRUCT);
+        assert_eq!(Uuid::parse_struct(UUID_V4_STRUCT).unwrap(), uuid);
+
+        // Leading zeros are optional
+        let uuid = Uuid::parse_struct("{0x1,0x2,0x3,{0x4,0x5,0x6,0x7,0x8,0x9,0xa,0xb}}").unwrap();
+        assert_eq!(
+            uuid.to_struct(&mut [0; 68]),
+            "{0x00000001,0x0002,0x0003,{0x04,0x05,0x06,0x07,0x08,0x09,0x0a,0x0b}}"
+        );
+
+        let bad = &[
+            ("", 0),
+            (
+                "{0x662aa7c71,0x7598,0x4d56,{0x8b,0xcc,0xa7,0x2c,0x30,0xf9,0x98,0xa2}}",
+                11,
+            ),
+            (
+                "{0x662aa7c7,0x7598,0x4d56,{0x8b,0xcc,0xa7,0x2c,0x30,0xf9,0x98}}",
+                61,
+            ),
+            (
+                "{0x662aa7c7,0x7598,0x4d56,{0x8b,0xcc,0xa7,0x2c,0x30,0xf9,0x98,0xa2}}}",
+                68,
+            ),
+            (
+                "{662aa7c7,0x7598,0x4d56,{0x8b,0xcc,0xa7,0x2c,0x30,0xf9,0x98,0xa2}}",
+                1,
+            ),
+            (
+                "{0x,0x7598,0x4d56,{0x8b,0xcc,0xa7,0x2c,0x30,0xf9,0x98,0xa2}}",
+                3,
+            ),
+        ];
+        for (s, index) in bad {
+            assert_eq!(
+                Uuid::parse_struct(s).unwrap_err().kind(),
+                ParseErrorKind::InvalidStructSyntax { index: *index },
+                "{}",
+                s
+            );
+        }
+    }
+}
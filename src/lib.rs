@@ -2,22 +2,57 @@
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 use core::{
-    convert::TryInto,
+    convert::{TryFrom, TryInto},
     fmt,
     str::{from_utf8_unchecked_mut, FromStr},
 };
 
 use hex_simd::{decode_inplace, AsciiCase::Lower, Out};
-use md5::{Digest, Md5};
 #[cfg(feature = "getrandom")]
 use rand_chacha::rand_core::OsRng;
 use rand_chacha::{
-    rand_core::{RngCore, SeedableRng},
+    rand_core::{CryptoRng, RngCore, SeedableRng},
     ChaChaRng,
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use sha1::Sha1;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::context::FileClockStore;
+pub use crate::context::{ClockState, ClockStore, TimeContext};
+pub use crate::generator::{Monotonicity, V7Generator};
+pub use crate::name::NameBasedBuilder;
+pub use crate::nonnil::NonNilUuid;
+#[cfg(all(feature = "std", feature = "getrandom"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "getrandom"))))]
+pub use crate::thread::ThreadRng;
+pub use crate::timestamp::{Timestamp, TimestampRangeError, UuidTimestamp};
+pub use crate::typed::{TypedUuid, UuidTag};
+pub use crate::typeid::PrefixedId;
+pub use crate::versioned::{UuidV4, UuidV5, UuidV7};
+/// Re-export of the `rand_core` version used by nuuid,
+/// for implementing [`RngCore`] on your own random sources.
+pub use rand_core;
+
+// Must come first, for the macros
+#[macro_use]
+mod wrapper;
+
+mod batch;
+mod context;
+mod encoding;
+mod generator;
+mod guid;
+mod name;
+mod nonnil;
+#[cfg(all(feature = "std", feature = "getrandom"))]
+mod thread;
+mod timestamp;
+mod typed;
+mod typeid;
+mod ulid;
+mod versioned;
 
 const UUID_STR_LENGTH: usize = 36;
 const UUID_URN_LENGTH: usize = 45;
@@ -25,6 +60,7 @@ const UUID_BRACED_LENGTH: usize = 38;
 const UUID_SIMPLE_LENGTH: usize = 32;
 const UUID_URN: &str = "urn:uuid:";
 const UUID_URN_PREFIX: usize = UUID_URN.len();
+const UUID_HYPHENS: [usize; 4] = [8, 13, 18, 23];
 
 /// The predefined DNS namespace, 6ba7b810-9dad-11d1-80b4-00c04fd430c8.
 pub const NAMESPACE_DNS: Uuid = Uuid::from_bytes([
@@ -50,6 +86,9 @@ pub const NAMESPACE_X500: Uuid = Uuid::from_bytes([
 pub type Bytes = [u8; 16];
 
 /// A CSPRNG suitable for generating UUID's.
+///
+/// [`Uuid::new_v4_rng`], [`V7Generator`], and [`TimeContext`] accept any
+/// [`RngCore`], this is provided for convenience.
 #[derive(Debug, Clone)]
 pub struct Rng(ChaChaRng);
 
@@ -67,14 +106,32 @@ impl Rng {
     pub fn from_seed(seed: [u8; 32]) -> Self {
         Self(ChaChaRng::from_seed(seed))
     }
+}
+
+impl RngCore for Rng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
 
-    /// Forward to rand's fill_bytes
     #[inline]
     fn fill_bytes(&mut self, dest: &mut [u8]) {
         self.0.fill_bytes(dest)
     }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.0.try_fill_bytes(dest)
+    }
 }
 
+impl CryptoRng for Rng {}
+
 #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
 #[cfg(feature = "getrandom")]
 impl Default for Rng {
@@ -85,7 +142,7 @@ impl Default for Rng {
 }
 
 /// UUID Variants
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[non_exhaustive]
 pub enum Variant {
     /// Reserved for NCS backward compatibility.
@@ -113,7 +170,7 @@ impl fmt::Display for Variant {
 }
 
 /// UUID Version
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[non_exhaustive]
 pub enum Version {
     /// Special case for the nil UUID.
@@ -134,20 +191,14 @@ pub enum Version {
     /// Version 5, SHA-1 name based.
     Sha1,
 
-    /// Version 6, re-ordered version of [`Version::time`] for DB locality.
-    #[cfg(feature = "experimental_uuid")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    /// Version 6, reordered version of [`Version::Time`] for DB locality.
     Database,
 
-    /// Version 7, unix time based.
-    #[cfg(feature = "experimental_uuid")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    /// Version 7, UNIX time based.
     UnixTime,
 
-    /// Version 8, experimental or vendor specific format
-    #[cfg(feature = "experimental_uuid")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
-    Vendor,
+    /// Version 8, custom experimental or vendor specific format.
+    Custom,
 
     /// Reserved versions. Currently, versions 9-15 are reserved.
     Reserved,
@@ -163,25 +214,270 @@ impl fmt::Display for Version {
             Version::Random => write!(f, "Random"),
             Version::Sha1 => write!(f, "Sha1"),
 
-            #[cfg(feature = "experimental_uuid")]
             Version::Database => write!(f, "Database"),
-            #[cfg(feature = "experimental_uuid")]
             Version::UnixTime => write!(f, "UnixTime"),
-            #[cfg(feature = "experimental_uuid")]
-            Version::Vendor => write!(f, "Vendor"),
-
+            Version::Custom => write!(f, "Custom"),
             Version::Reserved => write!(f, "Reserved"),
         }
     }
 }
 
+/// DCE Security UUID local domains, see [`Uuid::new_v2`] for details.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Domain {
+    /// The local identifier is a POSIX UID.
+    Person = 0,
+
+    /// The local identifier is a POSIX GID.
+    Group,
+
+    /// The local identifier is an organization defined value.
+    Org,
+}
+
+impl fmt::Display for Domain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Domain::Person => write!(f, "Person"),
+            Domain::Group => write!(f, "Group"),
+            Domain::Org => write!(f, "Org"),
+        }
+    }
+}
+
+/// UUID string formats, see [`Uuid::parse`] for details.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Format {
+    /// `662aa7c775984d568bcca72c30f998a2`
+    Simple,
+
+    /// `662aa7c7-7598-4d56-8bcc-a72c30f998a2`
+    Hyphenated,
+
+    /// `{662aa7c7-7598-4d56-8bcc-a72c30f998a2}`
+    Braced,
+
+    /// `urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2`
+    Urn,
+}
+
+/// The kind of error that occurred while parsing a UUID
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input was not a valid length for any supported format.
+    InvalidLength {
+        /// Length of the input, in bytes.
+        len: usize,
+    },
+
+    /// The input contained a character that was not a hexadecimal digit.
+    InvalidCharacter {
+        /// The invalid character.
+        found: char,
+
+        /// Byte index of the invalid character.
+        index: usize,
+    },
+
+    /// The input contained a hyphen where a hexadecimal digit was expected.
+    MisplacedHyphen {
+        /// Byte index of the hyphen.
+        index: usize,
+    },
+
+    /// The input contained something else where a hyphen was expected.
+    MissingHyphen {
+        /// The character found instead of a hyphen.
+        found: char,
+
+        /// Byte index of the character.
+        index: usize,
+    },
+
+    /// The input was the length of a URN, but did not start with `urn:uuid:`.
+    InvalidUrnPrefix,
+
+    /// The input was the length of a braced UUID, but was not surrounded by
+    /// `{` and `}`.
+    UnbalancedBraces,
+
+    /// The input was not valid Microsoft GUID structure notation,
+    /// see [`Uuid::parse_struct`].
+    InvalidStructSyntax {
+        /// Byte index of the first invalid character, or the input length if
+        /// the input ended early.
+        index: usize,
+    },
+
+    /// The input contained non-ASCII characters.
+    NonAscii {
+        /// Byte index of the first non-ASCII byte.
+        index: usize,
+    },
+
+    /// The input was not the correct length for a compact encoding,
+    /// such as [`Uuid::parse_base32`].
+    InvalidEncodedLength {
+        /// Length of the input, in bytes.
+        len: usize,
+
+        /// The expected length, in bytes.
+        expected: usize,
+    },
+
+    /// The input contained a character that was not a digit of a compact
+    /// encoding, such as [`Uuid::parse_base32`].
+    InvalidDigit {
+        /// The invalid character.
+        found: char,
+
+        /// Byte index of the invalid character.
+        index: usize,
+    },
+
+    /// The input decoded to a value too large to fit in a UUID.
+    Overflow,
+
+    /// The input had an invalid [`PrefixedId`] prefix.
+    InvalidPrefix {
+        /// Byte index of the invalid character, or the maximum prefix length
+        /// if the prefix was too long.
+        index: usize,
+    },
+
+    /// The UUID was valid, but not the required version,
+    /// such as for a [`TypedUuid`].
+    WrongVersion {
+        /// The required version.
+        expected: Version,
+
+        /// The version of the UUID.
+        found: Version,
+    },
+
+    /// The UUID was valid, but not the required variant,
+    /// such as for a [`UuidV4`].
+    WrongVariant {
+        /// The required variant.
+        expected: Variant,
+
+        /// The variant of the UUID.
+        found: Variant,
+    },
+
+    /// The UUID was valid, but nil, such as for a [`NonNilUuid`].
+    Nil,
+}
+
 /// Error parsing UUID
-#[derive(Debug)]
-pub struct ParseUuidError;
+///
+/// See [`ParseUuidError::kind`] for details on what went wrong.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ParseUuidError {
+    kind: ParseErrorKind,
+}
+
+impl ParseUuidError {
+    #[inline]
+    const fn new(kind: ParseErrorKind) -> Self {
+        Self { kind }
+    }
+
+    /// The kind of error that occurred
+    #[inline]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// A short description of the error, without any details.
+    ///
+    /// Unlike `Display`, this is usable in `const` contexts.
+    pub const fn message(&self) -> &'static str {
+        match self.kind {
+            ParseErrorKind::InvalidLength { .. } => "invalid UUID length",
+            ParseErrorKind::InvalidCharacter { .. } => {
+                "invalid character, expected a hexadecimal digit"
+            }
+            ParseErrorKind::MisplacedHyphen { .. } => {
+                "unexpected hyphen, expected a hexadecimal digit"
+            }
+            ParseErrorKind::MissingHyphen { .. } => "invalid character, expected a hyphen",
+            ParseErrorKind::InvalidUrnPrefix => "invalid URN prefix",
+            ParseErrorKind::UnbalancedBraces => "unbalanced braces",
+            ParseErrorKind::InvalidStructSyntax { .. } => "invalid GUID structure syntax",
+            ParseErrorKind::NonAscii { .. } => "non-ASCII character",
+            ParseErrorKind::InvalidEncodedLength { .. } => "invalid encoded UUID length",
+            ParseErrorKind::InvalidDigit { .. } => "invalid digit",
+            ParseErrorKind::Overflow => "value too large for a UUID",
+            ParseErrorKind::InvalidPrefix { .. } => "invalid prefix",
+            ParseErrorKind::WrongVersion { .. } => "wrong UUID version",
+            ParseErrorKind::WrongVariant { .. } => "wrong UUID variant",
+            ParseErrorKind::Nil => "nil UUID",
+        }
+    }
+}
 
 impl fmt::Display for ParseUuidError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "ParseUuidError")
+        match self.kind {
+            ParseErrorKind::InvalidLength { len } => write!(
+                f,
+                "invalid UUID length {}, expected {}, {}, {}, or {}",
+                len, UUID_SIMPLE_LENGTH, UUID_STR_LENGTH, UUID_BRACED_LENGTH, UUID_URN_LENGTH
+            ),
+            ParseErrorKind::InvalidCharacter { found, index } => write!(
+                f,
+                "invalid character {:?} at index {}, expected a hexadecimal digit",
+                found, index
+            ),
+            ParseErrorKind::MisplacedHyphen { index } => write!(
+                f,
+                "unexpected hyphen at index {}, expected a hexadecimal digit",
+                index
+            ),
+            ParseErrorKind::MissingHyphen { found, index } => write!(
+                f,
+                "invalid character {:?} at index {}, expected a hyphen",
+                found, index
+            ),
+            ParseErrorKind::InvalidUrnPrefix => {
+                write!(f, "invalid URN prefix, expected {:?}", UUID_URN)
+            }
+            ParseErrorKind::UnbalancedBraces => {
+                write!(
+                    f,
+                    "unbalanced braces, expected UUID surrounded by {{ and }}"
+                )
+            }
+            ParseErrorKind::InvalidStructSyntax { index } => {
+                write!(f, "invalid GUID structure syntax at index {}", index)
+            }
+            ParseErrorKind::NonAscii { index } => {
+                write!(f, "non-ASCII character at index {}", index)
+            }
+            ParseErrorKind::InvalidEncodedLength { len, expected } => write!(
+                f,
+                "invalid encoded UUID length {}, expected {}",
+                len, expected
+            ),
+            ParseErrorKind::InvalidDigit { found, index } => {
+                write!(f, "invalid digit {:?} at index {}", found, index)
+            }
+            ParseErrorKind::Overflow => write!(f, "value too large for a UUID"),
+            ParseErrorKind::InvalidPrefix { index } => {
+                write!(f, "invalid prefix at index {}", index)
+            }
+            ParseErrorKind::WrongVersion { expected, found } => {
+                write!(f, "wrong UUID version {}, expected {}", found, expected)
+            }
+            ParseErrorKind::WrongVariant { expected, found } => {
+                write!(f, "wrong UUID variant {}, expected {}", found, expected)
+            }
+            ParseErrorKind::Nil => write!(f, "UUID was nil"),
+        }
     }
 }
 
@@ -189,6 +485,85 @@ impl fmt::Display for ParseUuidError {
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl std::error::Error for ParseUuidError {}
 
+/// Find the invalid character in `s`, after parsing it failed.
+///
+/// `offset` is the index of `s` in the original input, and `hyphens` are the
+/// indices in `s` where hyphens are expected.
+///
+/// If not `strict`, whatever is at `hyphens` is ignored.
+#[cold]
+fn invalid_hex(s: &[u8], offset: usize, hyphens: &[usize], strict: bool) -> ParseUuidError {
+    let (index, found, hyphen) = s
+        .iter()
+        .enumerate()
+        .map(|(i, b)| (i, *b, hyphens.contains(&i)))
+        .find(|(_, b, hyphen)| match hyphen {
+            true => strict && *b != b'-',
+            false => !b.is_ascii_hexdigit(),
+        })
+        .expect("BUG: Parsing failed without an invalid character");
+    let index = index + offset;
+    ParseUuidError::new(match (found, hyphen) {
+        (found, true) => ParseErrorKind::MissingHyphen {
+            found: found.into(),
+            index,
+        },
+        (b'-', false) => ParseErrorKind::MisplacedHyphen { index },
+        (found, false) => ParseErrorKind::InvalidCharacter {
+            found: found.into(),
+            index,
+        },
+    })
+}
+
+/// Decode the hex digit at `s[index]`, for [`Uuid::parse_const`].
+const fn hex_value(s: &[u8], index: usize) -> Result<u8, ParseUuidError> {
+    match s[index] {
+        b @ b'0'..=b'9' => Ok(b - b'0'),
+        b @ b'a'..=b'f' => Ok(b - b'a' + 10),
+        b @ b'A'..=b'F' => Ok(b - b'A' + 10),
+        b'-' => Err(ParseUuidError::new(ParseErrorKind::MisplacedHyphen {
+            index,
+        })),
+        b => Err(ParseUuidError::new(ParseErrorKind::InvalidCharacter {
+            found: b as char,
+            index,
+        })),
+    }
+}
+
+/// Create a [`Uuid`] from a string literal at compile time.
+///
+/// Supports the same formats as [`Uuid::parse`],
+/// and fails to compile if the string is not a valid UUID.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{uuid, Uuid};
+/// const EFI_SYSTEM_PARTITION: Uuid = uuid!("C12A7328-F81F-11D2-BA4B-00A0C93EC93B");
+///
+/// let uuid = uuid!("{662aa7c7-7598-4d56-8bcc-a72c30f998a2}");
+/// assert_eq!(uuid, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+/// ```
+///
+/// Invalid UUIDs fail to compile
+///
+/// ```rust,compile_fail
+/// # use nuuid::uuid;
+/// let uuid = uuid!("662aa7c7-7598-4d56-8bcc-a72c30f998az");
+/// ```
+#[macro_export]
+macro_rules! uuid {
+    ($uuid:expr) => {{
+        const UUID: $crate::Uuid = match $crate::Uuid::parse_const($uuid) {
+            Ok(uuid) => uuid,
+            Err(e) => panic!("{}", e.message()),
+        };
+        UUID
+    }};
+}
+
 /// Universally Unique Identifier, or UUID.
 ///
 /// This type is `repr(transparent)` and guaranteed to have the same layout
@@ -264,10 +639,8 @@ impl Uuid {
 
     /// The special Max UUID, where all bits are set to one.
     #[inline]
-    #[cfg(feature = "experimental_uuid")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
     pub const fn max() -> Self {
-        Uuid([1; 16])
+        Uuid([0xFF; 16])
     }
 
     /// Create a UUID from bytes.
@@ -309,6 +682,125 @@ impl Uuid {
         self.swap_endian().to_bytes()
     }
 
+    /// Create a UUID from its fields.
+    ///
+    /// These are the fields of the Microsoft `GUID` structure,
+    /// `Data1` through `Data4`, and are laid out big-endian.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::from_fields(
+    ///     0x662aa7c7,
+    ///     0x7598,
+    ///     0x4d56,
+    ///     [0x8b, 0xcc, 0xa7, 0x2c, 0x30, 0xf9, 0x98, 0xa2],
+    /// );
+    /// assert_eq!(uuid, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+    /// ```
+    #[inline]
+    pub const fn from_fields(d1: u32, d2: u16, d3: u16, d4: [u8; 8]) -> Self {
+        let (d1, d2, d3) = (d1.to_be_bytes(), d2.to_be_bytes(), d3.to_be_bytes());
+        Self([
+            d1[0], d1[1], d1[2], d1[3], d2[0], d2[1], d3[0], d3[1], //
+            d4[0], d4[1], d4[2], d4[3], d4[4], d4[5], d4[6], d4[7],
+        ])
+    }
+
+    /// Return the UUID as its fields.
+    ///
+    /// See [`Uuid::from_fields`] for details.
+    #[inline]
+    pub const fn to_fields(self) -> (u32, u16, u16, [u8; 8]) {
+        let b = self.0;
+        (
+            u32::from_be_bytes([b[0], b[1], b[2], b[3]]),
+            u16::from_be_bytes([b[4], b[5]]),
+            u16::from_be_bytes([b[6], b[7]]),
+            [b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]],
+        )
+    }
+
+    /// Create a UUID from its fields, where `d1`, `d2`, and `d3` are
+    /// little-endian.
+    ///
+    /// The bytes of `d1`, `d2`, and `d3` are reversed relative to
+    /// [`Uuid::from_fields`], so this is the same as [`Uuid::from_bytes_me`]
+    /// on the bytes `from_fields` would produce. `d4` is unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::from_fields_le(
+    ///     0xc7a72a66,
+    ///     0x9875,
+    ///     0x564d,
+    ///     [0x8b, 0xcc, 0xa7, 0x2c, 0x30, 0xf9, 0x98, 0xa2],
+    /// );
+    /// assert_eq!(uuid, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+    /// ```
+    #[inline]
+    pub const fn from_fields_le(d1: u32, d2: u16, d3: u16, d4: [u8; 8]) -> Self {
+        Self::from_fields(d1.swap_bytes(), d2.swap_bytes(), d3.swap_bytes(), d4)
+    }
+
+    /// Return the UUID as its fields, where `d1`, `d2`, and `d3` are
+    /// little-endian.
+    ///
+    /// See [`Uuid::from_fields_le`] for details.
+    #[inline]
+    pub const fn to_fields_le(self) -> (u32, u16, u16, [u8; 8]) {
+        let (d1, d2, d3, d4) = self.to_fields();
+        (d1.swap_bytes(), d2.swap_bytes(), d3.swap_bytes(), d4)
+    }
+
+    /// Create a UUID from a 128-bit number.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::from_u128(0x662aa7c7_7598_4d56_8bcc_a72c30f998a2);
+    /// assert_eq!(uuid, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+    /// ```
+    #[inline]
+    pub const fn from_u128(v: u128) -> Self {
+        Self(v.to_be_bytes())
+    }
+
+    /// Return the UUID as a 128-bit number.
+    ///
+    /// See [`Uuid::from_u128`] for details.
+    #[inline]
+    pub const fn as_u128(self) -> u128 {
+        u128::from_be_bytes(self.0)
+    }
+
+    /// Create a UUID from the high and low 64 bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::from_u64_pair(0x662aa7c775984d56, 0x8bcca72c30f998a2);
+    /// assert_eq!(uuid, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+    /// ```
+    #[inline]
+    pub const fn from_u64_pair(high: u64, low: u64) -> Self {
+        Self::from_u128(((high as u128) << 64) | low as u128)
+    }
+
+    /// Return the UUID as the high and low 64 bits.
+    ///
+    /// See [`Uuid::from_u64_pair`] for details.
+    #[inline]
+    pub const fn as_u64_pair(self) -> (u64, u64) {
+        let v = self.as_u128();
+        ((v >> 64) as u64, v as u64)
+    }
+
     /// Returns true if the UUID is nil.
     #[inline]
     pub const fn is_nil(self) -> bool {
@@ -317,6 +809,14 @@ impl Uuid {
         u128::from_ne_bytes(self.0) == 0
     }
 
+    /// Returns true if the UUID is max.
+    #[inline]
+    pub const fn is_max(self) -> bool {
+        // Done this way for const reasons
+        // max is max regardless of byte order
+        u128::from_ne_bytes(self.0) == u128::MAX
+    }
+
     /// The UUID Variant
     ///
     /// # Warning
@@ -372,43 +872,51 @@ impl Uuid {
             (false, true, false, false) => Version::Random,
             (false, true, false, true) => Version::Sha1,
 
-            #[cfg(feature = "experimental_uuid")]
             (false, true, true, false) => Version::Database,
 
-            #[cfg(feature = "experimental_uuid")]
             (false, true, true, true) => Version::UnixTime,
 
-            #[cfg(feature = "experimental_uuid")]
-            (true, false, false, false) => Version::Vendor,
+            (true, false, false, false) => Version::Custom,
 
             _ => Version::Reserved,
         }
     }
 
-    /// The 60-bit UUID timestamp
+    /// The UUID timestamp
     ///
-    /// This value will only make sense for [`Version::Time`] or
-    /// [`Version::Database`] UUIDs
+    /// Returns `None` for UUID versions without a timestamp.
     ///
-    /// The value of this will depend on [`Uuid::version`]
+    /// The epoch and resolution of the timestamp depend on [`Uuid::version`],
+    /// see [`UuidTimestamp`] for details.
+    ///
+    /// For [`Version::Dce`] UUIDs, the low 32 bits of the timestamp are not
+    /// stored, and are zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, UuidTimestamp};
+    /// let uuid = Uuid::new_v1(138788330336896890, 8648, *b"world!");
+    /// assert_eq!(uuid.timestamp(), Some(UuidTimestamp::Gregorian(138788330336896890)));
+    ///
+    /// assert_eq!(Uuid::nil().timestamp(), None);
+    /// ```
     #[inline]
-    pub const fn timestamp(self) -> u64 {
+    pub const fn timestamp(self) -> Option<UuidTimestamp> {
         match self.version() {
-            #[cfg(feature = "experimental_uuid")]
-            Version::Database => u64::from_be_bytes([
+            // The low 32 bits are replaced by the local identifier
+            Version::Dce => Some(UuidTimestamp::Gregorian(u64::from_be_bytes([
                 // Clear version bits
                 self.0[6] & 0xF,
                 self.0[7],
                 self.0[4],
                 self.0[5],
-                self.0[0],
-                self.0[1],
-                self.0[2],
-                self.0[3],
-            ]),
-            // #[cfg(feature = "experimental_uuid")]
-            // Version::UnixTime => todo!(),
-            _ => u64::from_be_bytes([
+                0,
+                0,
+                0,
+                0,
+            ]))),
+            Version::Time => Some(UuidTimestamp::Gregorian(u64::from_be_bytes([
                 // Clear version bits
                 self.0[6] & 0xF,
                 self.0[7],
@@ -418,7 +926,20 @@ impl Uuid {
                 self.0[1],
                 self.0[2],
                 self.0[3],
-            ]),
+            ]))),
+            Version::Database => Some(UuidTimestamp::Gregorian(
+                // time_high and time_mid
+                u64::from_be_bytes([
+                    0, 0, self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5],
+                ]) << 12
+                    // time_low, clearing version bits
+                    | ((self.0[6] & 0xF) as u64) << 8
+                    | self.0[7] as u64,
+            )),
+            Version::UnixTime => Some(UuidTimestamp::UnixMillis(u64::from_be_bytes([
+                0, 0, self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5],
+            ]))),
+            _ => None,
         }
     }
 
@@ -446,6 +967,36 @@ impl Uuid {
         ]
     }
 
+    /// The 32-bit local identifier of a [`Version::Dce`] UUID
+    ///
+    /// Returns `None` for other UUID versions.
+    ///
+    /// See [`Uuid::new_v2`] for details.
+    #[inline]
+    pub const fn local_id(self) -> Option<u32> {
+        match self.version() {
+            Version::Dce => Some(u32::from_be_bytes([
+                self.0[0], self.0[1], self.0[2], self.0[3],
+            ])),
+            _ => None,
+        }
+    }
+
+    /// The local domain of a [`Version::Dce`] UUID
+    ///
+    /// Returns `None` for other UUID versions, or unknown domains.
+    ///
+    /// See [`Uuid::new_v2`] for details.
+    #[inline]
+    pub const fn domain(self) -> Option<Domain> {
+        match (self.version(), self.0[9]) {
+            (Version::Dce, 0) => Some(Domain::Person),
+            (Version::Dce, 1) => Some(Domain::Group),
+            (Version::Dce, 2) => Some(Domain::Org),
+            _ => None,
+        }
+    }
+
     /// Write UUID as a lowercase ASCII string into `buf`, and returns it as a
     /// string.
     ///
@@ -570,6 +1121,9 @@ impl Uuid {
     /// - "Hyphenate" `662aa7c7-7598-4d56-8bcc-a72c30f998a2`
     /// - "Simple" `662aa7c775984d568bcca72c30f998a2`
     ///
+    /// The URN prefix, braces, and hyphens are required to be exactly where
+    /// they belong. See [`Uuid::parse_lenient`] for parsing UUIDs that don't.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -585,28 +1139,193 @@ impl Uuid {
     ///
     /// Uuid::parse("{662aa7c7-7598-4d56-8bcc-a72c30f998a2}").unwrap();
     /// Uuid::parse("{662AA7C7-7598-4D56-8BCC-A72C30F998A2}").unwrap();
+    ///
+    /// Uuid::parse("(662aa7c7-7598-4d56-8bcc-a72c30f998a2]").unwrap_err();
     /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `s` is not a valid UUID, see [`ParseErrorKind`] for details.
+    #[inline]
     pub fn parse(s: &str) -> Result<Self, ParseUuidError> {
+        Uuid::parse_bytes(s.as_bytes(), true).map(|(uuid, _)| uuid)
+    }
+
+    /// [`Uuid::parse`], but also return the [`Format`] of the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Format, Uuid};
+    /// let (_, format) = Uuid::parse_with_format("{662aa7c7-7598-4d56-8bcc-a72c30f998a2}").unwrap();
+    /// assert_eq!(format, Format::Braced);
+    /// ```
+    #[inline]
+    pub fn parse_with_format(s: &str) -> Result<(Self, Format), ParseUuidError> {
+        Uuid::parse_bytes(s.as_bytes(), true)
+    }
+
+    /// [`Uuid::parse`], but from ASCII bytes.
+    ///
+    /// This avoids needing to validate `s` as UTF-8 first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let packet = b"GET 662aa7c7-7598-4d56-8bcc-a72c30f998a2\r\n";
+    /// let uuid = Uuid::parse_ascii(&packet[4..40]).unwrap();
+    /// assert_eq!(uuid, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+    /// ```
+    #[inline]
+    pub fn parse_ascii(s: &[u8]) -> Result<Self, ParseUuidError> {
+        Uuid::parse_bytes(s, true).map(|(uuid, _)| uuid)
+    }
+
+    /// [`Uuid::parse_lenient`], but from ASCII bytes.
+    ///
+    /// See [`Uuid::parse_ascii`] for details.
+    #[inline]
+    pub fn parse_ascii_lenient(s: &[u8]) -> Result<Self, ParseUuidError> {
+        Uuid::parse_bytes(s, false).map(|(uuid, _)| uuid)
+    }
+
+    /// [`Uuid::parse`], but usable in `const` contexts.
+    ///
+    /// This is slower than [`Uuid::parse`], and should only be used in `const`
+    /// contexts. See also the [`uuid!`] macro.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// const UUID: Uuid = match Uuid::parse_const("662aa7c7-7598-4d56-8bcc-a72c30f998a2") {
+    ///     Ok(uuid) => uuid,
+    ///     Err(_) => panic!("Invalid UUID"),
+    /// };
+    /// ```
+    pub const fn parse_const(s: &str) -> Result<Self, ParseUuidError> {
+        let s = s.as_bytes();
+
+        // Error if input is not ASCII
+        let mut i = 0;
+        while i < s.len() {
+            if !s[i].is_ascii() {
+                return Err(ParseUuidError::new(ParseErrorKind::NonAscii { index: i }));
+            }
+            i += 1;
+        }
+
+        let (offset, hyphens) = match s.len() {
+            UUID_URN_LENGTH => {
+                let urn = UUID_URN.as_bytes();
+                let mut i = 0;
+                while i < UUID_URN_PREFIX {
+                    if !s[i].eq_ignore_ascii_case(&urn[i]) {
+                        return Err(ParseUuidError::new(ParseErrorKind::InvalidUrnPrefix));
+                    }
+                    i += 1;
+                }
+                (UUID_URN_PREFIX, true)
+            }
+            UUID_BRACED_LENGTH => {
+                if s[0] != b'{' || s[s.len() - 1] != b'}' {
+                    return Err(ParseUuidError::new(ParseErrorKind::UnbalancedBraces));
+                }
+                (1, true)
+            }
+            UUID_STR_LENGTH => (0, true),
+            UUID_SIMPLE_LENGTH => (0, false),
+            len => return Err(ParseUuidError::new(ParseErrorKind::InvalidLength { len })),
+        };
+
+        let mut bytes = [0; 16];
+        let mut byte = 0;
+        let mut i = offset;
+        while byte < bytes.len() {
+            let pos = i - offset;
+            if hyphens && (pos == 8 || pos == 13 || pos == 18 || pos == 23) {
+                if s[i] != b'-' {
+                    return Err(ParseUuidError::new(ParseErrorKind::MissingHyphen {
+                        found: s[i] as char,
+                        index: i,
+                    }));
+                }
+                i += 1;
+                continue;
+            }
+            let hi = match hex_value(s, i) {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+            let lo = match hex_value(s, i + 1) {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+            bytes[byte] = (hi << 4) | lo;
+            byte += 1;
+            i += 2;
+        }
+
+        Ok(Uuid::from_bytes(bytes))
+    }
+
+    /// [`Uuid::parse`], but only using the input length to choose the format.
+    ///
+    /// The URN prefix, braces, and the characters where hyphens are expected
+    /// are ignored, whatever they are.
+    ///
+    /// This was the behavior of [`Uuid::parse`] in previous versions, and
+    /// should only be used for compatibility with existing invalid inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse_lenient("(662aa7c7-7598-4d56-8bcc-a72c30f998a2]").unwrap();
+    /// assert_eq!(uuid, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+    /// ```
+    #[inline]
+    pub fn parse_lenient(s: &str) -> Result<Self, ParseUuidError> {
+        Uuid::parse_bytes(s.as_bytes(), false).map(|(uuid, _)| uuid)
+    }
+
+    /// Parse ASCII `s`, returning the detected format.
+    ///
+    /// If `strict`, the URN prefix, braces, and hyphens are validated.
+    fn parse_bytes(s: &[u8], strict: bool) -> Result<(Self, Format), ParseUuidError> {
         // Error if input is not ASCII
-        if !s.is_ascii() {
-            return Err(ParseUuidError);
+        if let Some(index) = s.iter().position(|b| !b.is_ascii()) {
+            return Err(ParseUuidError::new(ParseErrorKind::NonAscii { index }));
         }
 
-        let s = match s.len() {
-            UUID_URN_LENGTH => &s[UUID_URN_PREFIX..],
-            UUID_BRACED_LENGTH => &s[1..s.len() - 1],
-            UUID_STR_LENGTH => s,
+        let (format, offset, s) = match s.len() {
+            UUID_URN_LENGTH => {
+                if strict && !s[..UUID_URN_PREFIX].eq_ignore_ascii_case(UUID_URN.as_bytes()) {
+                    return Err(ParseUuidError::new(ParseErrorKind::InvalidUrnPrefix));
+                }
+                (Format::Urn, UUID_URN_PREFIX, &s[UUID_URN_PREFIX..])
+            }
+            UUID_BRACED_LENGTH => {
+                if strict && (s[0] != b'{' || s[s.len() - 1] != b'}') {
+                    return Err(ParseUuidError::new(ParseErrorKind::UnbalancedBraces));
+                }
+                (Format::Braced, 1, &s[1..s.len() - 1])
+            }
+            UUID_STR_LENGTH => (Format::Hyphenated, 0, s),
             UUID_SIMPLE_LENGTH => {
-                return Ok(Uuid::from_bytes(
-                    u128::from_str_radix(s, 16)
-                        .map_err(|_| ParseUuidError)?
-                        .to_be_bytes(),
+                let mut raw = [0; UUID_SIMPLE_LENGTH];
+                raw.copy_from_slice(s);
+                let x = decode_inplace(&mut raw).map_err(|_| invalid_hex(s, 0, &[], strict))?;
+                return Ok((
+                    Uuid::from_bytes(x.try_into().expect("BUG: Invalid length")),
+                    Format::Simple,
                 ));
             }
-            _ => return Err(ParseUuidError),
+            len => return Err(ParseUuidError::new(ParseErrorKind::InvalidLength { len })),
         };
-        let s = s.as_bytes();
 
+        let hyphens = !strict || UUID_HYPHENS.iter().all(|&i| s[i] == b'-');
         let mut raw = [0; UUID_SIMPLE_LENGTH];
         // "00000000-0000-0000-0000-000000000000"
         //          9    14   19   24
@@ -635,8 +1354,13 @@ impl Uuid {
         // Low bits of the timestamp
         raw[..8].copy_from_slice(&s[..8]);
 
-        let x = decode_inplace(&mut raw).map_err(|_| ParseUuidError)?;
-        Ok(Uuid::from_bytes(x.try_into().map_err(|_| ParseUuidError)?))
+        match decode_inplace(&mut raw) {
+            Ok(x) if hyphens => Ok((
+                Uuid::from_bytes(x.try_into().expect("BUG: Invalid length")),
+                format,
+            )),
+            _ => Err(invalid_hex(s, offset, &UUID_HYPHENS, strict)),
+        }
     }
 
     /// Parse a [`Uuid`] from a string that is in mixed-endian
@@ -656,7 +1380,10 @@ impl Uuid {
     ///
     /// This requires the `getrandom` feature.
     ///
-    /// If generating a lot of UUID's very quickly, prefer [`Uuid::new_v4_rng`].
+    /// With the `std` feature this uses the thread-local `ThreadRng`,
+    /// otherwise the OS random number generator is used directly for
+    /// every UUID, and [`Uuid::new_v4_rng`] should be preferred if
+    /// generating a lot of UUID's very quickly.
     ///
     /// # Example
     ///
@@ -668,20 +1395,25 @@ impl Uuid {
     #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
     #[inline]
     pub fn new_v4() -> Self {
-        let mut uuid = Uuid::nil();
-        OsRng.fill_bytes(&mut uuid.0);
-        uuid.set_variant(Variant::Rfc4122);
-        uuid.set_version(Version::Random);
-        uuid
+        #[cfg(feature = "std")]
+        {
+            Uuid::new_v4_rng(&mut ThreadRng::new())
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Uuid::new_v4_rng(&mut OsRng)
+        }
     }
 
-    /// Create a new Version 4(Random) UUID, using the provided [`Rng`]
+    /// Create a new Version 4(Random) UUID, using the provided random source,
+    /// such as [`Rng`].
     ///
     /// This method is useful if you need to generate a lot of UUID's very
     /// quickly, since it won't create and seed a new RNG each time.
     ///
-    /// Providing a good seed is left to you, however.
-    /// If a bad seed is used, the resulting UUIDs may not be
+    /// Any [`RngCore`] can be used, such as a hardware random number generator.
+    /// Providing a good source of randomness is left to you, however.
+    /// If a bad seed or RNG is used, the resulting UUIDs may not be
     /// sufficiently random or unique.
     ///
     /// # Example
@@ -695,7 +1427,7 @@ impl Uuid {
     /// }
     /// ```
     #[inline]
-    pub fn new_v4_rng(rng: &mut Rng) -> Self {
+    pub fn new_v4_rng<R: RngCore + ?Sized>(rng: &mut R) -> Self {
         let mut uuid = Uuid::nil();
         rng.fill_bytes(&mut uuid.0);
         uuid.set_variant(Variant::Rfc4122);
@@ -703,6 +1435,82 @@ impl Uuid {
         uuid
     }
 
+    /// Fill `uuids` with new Version 4(Random) UUIDs, using the provided
+    /// random source, such as [`Rng`].
+    ///
+    /// This is equivalent to calling [`Uuid::new_v4_rng`] for every UUID,
+    /// but is faster for large numbers of UUIDs because all the random bytes
+    /// are generated at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Rng, Uuid, Version};
+    /// # let seed = [0; 32];
+    /// let mut rng = Rng::from_seed(seed);
+    /// let mut uuids = vec![Uuid::nil(); 1000];
+    /// Uuid::fill_v4(&mut rng, &mut uuids);
+    /// assert!(uuids.iter().all(|u| u.version() == Version::Random));
+    /// ```
+    pub fn fill_v4<R: RngCore + ?Sized>(rng: &mut R, uuids: &mut [Uuid]) {
+        rng.fill_bytes(Uuid::slice_as_bytes_mut(uuids));
+        for uuid in uuids {
+            uuid.set_variant(Variant::Rfc4122);
+            uuid.set_version(Version::Random);
+        }
+    }
+
+    /// Fill `uuids` with new Version 7 UUIDs using the provided 48-bit
+    /// millisecond UNIX timestamp, and random `rand_a` and `rand_b` from the
+    /// provided random source, such as [`Rng`].
+    ///
+    /// This is equivalent to calling [`Uuid::new_v7`] with random values for
+    /// every UUID, but is faster for large numbers of UUIDs because all the
+    /// random bytes are generated at once.
+    ///
+    /// Every UUID has the same timestamp, and is **not** ordered relative to
+    /// the others, see [`V7Generator`] if this is required.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Rng, Uuid, Version};
+    /// # let (seed, TIMESTAMP) = ([0; 32], 0x17F22E279B0);
+    /// let mut rng = Rng::from_seed(seed);
+    /// let mut uuids = vec![Uuid::nil(); 1000];
+    /// Uuid::fill_v7(&mut rng, TIMESTAMP, &mut uuids);
+    /// assert!(uuids.iter().all(|u| u.version() == Version::UnixTime));
+    /// ```
+    pub fn fill_v7<R: RngCore + ?Sized>(rng: &mut R, timestamp: u64, uuids: &mut [Uuid]) {
+        // Truncate the highest 16 bits
+        // https://www.rfc-editor.org/rfc/rfc9562#section-5.7
+        let timestamp = (timestamp << 16).to_be_bytes();
+        rng.fill_bytes(Uuid::slice_as_bytes_mut(uuids));
+        for uuid in uuids {
+            uuid.0[..6].copy_from_slice(&timestamp[..6]);
+            uuid.set_variant(Variant::Rfc4122);
+            uuid.set_version(Version::UnixTime);
+        }
+    }
+
+    /// View `uuids` as one contiguous byte slice
+    #[inline]
+    fn slice_as_bytes(uuids: &[Uuid]) -> &[u8] {
+        let len = core::mem::size_of_val(uuids);
+        // Safety: `Uuid` is `repr(transparent)` over `[u8; 16]`,
+        // so a slice of them is a valid slice of `16 * len` bytes.
+        unsafe { core::slice::from_raw_parts(uuids.as_ptr().cast::<u8>(), len) }
+    }
+
+    /// View `uuids` as one contiguous mutable byte slice
+    #[inline]
+    fn slice_as_bytes_mut(uuids: &mut [Uuid]) -> &mut [u8] {
+        let len = core::mem::size_of_val(uuids);
+        // Safety: `Uuid` is `repr(transparent)` over `[u8; 16]`,
+        // so a slice of them is a valid slice of `16 * len` bytes.
+        unsafe { core::slice::from_raw_parts_mut(uuids.as_mut_ptr().cast::<u8>(), len) }
+    }
+
     /// Create a new Version 3 UUID with the provided name and namespace.
     ///
     /// # Note
@@ -718,17 +1526,13 @@ impl Uuid {
     /// ```
     #[inline]
     pub fn new_v3(namespace: Uuid, name: &[u8]) -> Self {
-        let mut hasher = Md5::new();
-        hasher.update(namespace.to_bytes());
-        hasher.update(name);
-        let mut uuid = Uuid::from_bytes(hasher.finalize().into());
-        uuid.set_version(Version::Md5);
-        uuid.set_variant(Variant::Rfc4122);
-        uuid
+        NameBasedBuilder::v3(namespace).update(name).finish()
     }
 
     /// Create a new Version 5 UUID with the provided name and namespace.
     ///
+    /// See [`NameBasedBuilder`] for names that aren't in memory all at once.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -737,13 +1541,7 @@ impl Uuid {
     /// ```
     #[inline]
     pub fn new_v5(namespace: Uuid, name: &[u8]) -> Self {
-        let mut hasher = Sha1::new();
-        hasher.update(namespace.to_bytes());
-        hasher.update(name);
-        let mut uuid = Uuid::from_bytes(hasher.finalize()[..16].try_into().unwrap());
-        uuid.set_version(Version::Sha1);
-        uuid.set_variant(Variant::Rfc4122);
-        uuid
+        NameBasedBuilder::v5(namespace).update(name).finish()
     }
 
     /// Create a new Version 1 UUID using the provided 60-bit timestamp,
@@ -753,6 +1551,9 @@ impl Uuid {
     ///
     /// The 2 high bits of `counter` are ignored
     ///
+    /// See [`Timestamp::to_gregorian`] for creating `timestamp`,
+    /// and [`TimeContext`] for managing `counter`.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -789,6 +1590,64 @@ impl Uuid {
         ])
     }
 
+    /// Create a new Version 2 DCE Security UUID using the provided local
+    /// domain and identifier, 60-bit timestamp, 6-bit clock sequence, and node.
+    ///
+    /// This is similar to Version 1 UUIDs (see [`Uuid::new_v1`]),
+    /// except that the low 32 bits of the timestamp are replaced with
+    /// `local_id`, and the low 8 bits of the clock sequence are replaced with
+    /// `domain`, as described in the
+    /// [DCE 1.1 specification](https://pubs.opengroup.org/onlinepubs/9696989899/chap5.htm#tagcjh_08_02_01_01).
+    ///
+    /// The 4 high bits of `timestamp` are ignored
+    ///
+    /// The 2 high bits of `clock_seq` are ignored
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Domain, Uuid};
+    /// # let (TIMESTAMP, RANDOM, RANDOM_OR_MAC) = (0, 0, [0; 6]);
+    /// let uuid = Uuid::new_v2(Domain::Person, 1000, TIMESTAMP, RANDOM, RANDOM_OR_MAC);
+    /// assert_eq!(uuid.local_id(), Some(1000));
+    /// assert_eq!(uuid.domain(), Some(Domain::Person));
+    /// ```
+    #[inline]
+    pub fn new_v2(
+        domain: Domain,
+        local_id: u32,
+        timestamp: u64,
+        clock_seq: u8,
+        node: [u8; 6],
+    ) -> Self {
+        let timestamp = timestamp.to_be_bytes();
+        let local_id = local_id.to_be_bytes();
+        Uuid::from_bytes([
+            // local_id, in place of time_low
+            local_id[0],
+            local_id[1],
+            local_id[2],
+            local_id[3],
+            // time_mid
+            timestamp[2],
+            timestamp[3],
+            // time_hi Version, ignore highest 4 bits, skip `set_version` and set the version
+            (timestamp[0] & 0xF) | (2u8 << 4),
+            timestamp[1],
+            // clock_seq_hi Variant, skip `set_variant` and set the variant
+            (clock_seq & 0x3F) | 0x80,
+            // domain, in place of clock_seq_low
+            domain as u8,
+            // Node
+            node[0],
+            node[1],
+            node[2],
+            node[3],
+            node[4],
+            node[5],
+        ])
+    }
+
     /// Create a new Version 6 UUID
     ///
     /// This is identical to Version 1 UUIDs (see [`Uuid::new_v1`]),
@@ -802,12 +1661,12 @@ impl Uuid {
     /// let uuid = Uuid::new_v6(TIMESTAMP, RANDOM, PSEUDO);
     /// ```
     #[inline]
-    #[cfg(feature = "experimental_uuid")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
     pub fn new_v6(timestamp: u64, counter: u16, node: [u8; 6]) -> Self {
         // Truncate the highest 4 bits
-        // https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#section-6.1-2.14
+        // https://www.rfc-editor.org/rfc/rfc9562#section-5.6
+        let time_low = (timestamp & 0xFFF) as u16;
         let timestamp = (timestamp << 4).to_be_bytes();
+        let time_low = time_low.to_be_bytes();
         let counter = counter.to_be_bytes();
 
         Uuid::from_bytes([
@@ -819,9 +1678,9 @@ impl Uuid {
             // time_mid
             timestamp[4],
             timestamp[5],
-            // time_low Version, shift 4 bits, skip `set_version` and set the version
-            (timestamp[6] >> 4) | (6u8 << 4),
-            timestamp[7],
+            // time_low Version, skip `set_version` and set the version
+            time_low[0] | (6u8 << 4),
+            time_low[1],
             // clock_seq_hi Variant, skip `set_variant` and set the variant
             (counter[0] & 0x3F) | 0x80,
             counter[1],
@@ -840,6 +1699,9 @@ impl Uuid {
     /// This is similar to Version 1 and 6 UUIDs, but uses the UNIX epoch
     /// timestamp source.
     ///
+    /// See [`Timestamp::to_unix_millis`] for creating `timestamp`,
+    /// and [`V7Generator`] for keeping UUIDs in creation order.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -848,11 +1710,9 @@ impl Uuid {
     /// let uuid = Uuid::new_v7(TIMESTAMP, RAND_A, RAND_B);
     /// ```
     #[inline]
-    #[cfg(feature = "experimental_uuid")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
     pub fn new_v7(timestamp: u64, rand_a: u16, rand_b: u64) -> Self {
         // Truncate the highest 16 bits
-        // https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#section-6.1-2.14
+        // https://www.rfc-editor.org/rfc/rfc9562#section-5.7
         let timestamp = (timestamp << 16).to_be_bytes();
         let rand_a = rand_a.to_be_bytes();
         let rand_b = rand_b.to_be_bytes();
@@ -892,14 +1752,159 @@ impl Uuid {
     /// let uuid = Uuid::new_v8(*b"I Am 16 bytes!!!");
     /// ```
     #[inline]
-    #[cfg(feature = "experimental_uuid")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
     pub fn new_v8(bytes: Bytes) -> Self {
         let mut uuid = Uuid::from_bytes(bytes);
         uuid.set_variant(Variant::Rfc4122);
-        uuid.set_version(Version::Vendor);
+        uuid.set_version(Version::Custom);
         uuid
     }
+
+    /// Create a new name based Version 8 UUID with the provided name and
+    /// namespace, using SHA-256.
+    ///
+    /// This is the same as [`Uuid::new_v5`], but with SHA-256 instead of SHA-1,
+    /// as described in
+    /// [RFC 9562 Appendix B.2](https://www.rfc-editor.org/rfc/rfc9562#appendix-B.2).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{uuid, NAMESPACE_DNS, Uuid};
+    /// let uuid = Uuid::new_v8_sha256(NAMESPACE_DNS, b"www.example.com");
+    /// assert_eq!(uuid, uuid!("5c146b14-3c52-8afd-938a-375d0df1fbf6"));
+    /// ```
+    #[cfg(feature = "sha2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha2")))]
+    #[inline]
+    pub fn new_v8_sha256(namespace: Uuid, name: &[u8]) -> Self {
+        NameBasedBuilder::v8_sha256(namespace).update(name).finish()
+    }
+
+    /// Create a new name based Version 8 UUID with the provided name and
+    /// namespace, using SHA-512.
+    ///
+    /// See [`Uuid::new_v8_sha256`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// let uuid = Uuid::new_v8_sha512(NAMESPACE_DNS, b"www.example.com");
+    /// ```
+    #[cfg(feature = "sha2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha2")))]
+    #[inline]
+    pub fn new_v8_sha512(namespace: Uuid, name: &[u8]) -> Self {
+        NameBasedBuilder::v8_sha512(namespace).update(name).finish()
+    }
+
+    /// Create a new keyed name based Version 8 UUID with the provided name and
+    /// namespace, using HMAC-SHA-256 with the secret `key`.
+    ///
+    /// Unlike [`Uuid::new_v5`] and [`Uuid::new_v8_sha256`], the UUID can't be
+    /// predicted from the name without knowing `key`, so names can't be
+    /// discovered by enumerating likely candidates.
+    ///
+    /// The UUID is the first 16 bytes of the HMAC over the namespace and name,
+    /// with the version and variant bits set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// let uuid = Uuid::new_v8_hmac_sha256(b"secret key", NAMESPACE_DNS, b"example.com");
+    /// assert_ne!(uuid, Uuid::new_v8_hmac_sha256(b"other key", NAMESPACE_DNS, b"example.com"));
+    /// ```
+    #[cfg(feature = "hmac")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+    #[inline]
+    pub fn new_v8_hmac_sha256(key: &[u8], namespace: Uuid, name: &[u8]) -> Self {
+        NameBasedBuilder::v8_hmac_sha256(key, namespace)
+            .update(name)
+            .finish()
+    }
+
+    /// Create a new Version 1 UUID using the current system time and
+    /// provided node.
+    ///
+    /// The clock sequence is tracked by a process-wide [`TimeContext`].
+    ///
+    /// This requires the `std` and `getrandom` features.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// # let RANDOM_OR_MAC = [0; 6];
+    /// let uuid = Uuid::now_v1(RANDOM_OR_MAC);
+    /// ```
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "getrandom"))))]
+    pub fn now_v1(node: [u8; 6]) -> Self {
+        let mut ctx = time_context();
+        let ctx = ctx.get_or_insert_with(|| TimeContext::new(Rng::new()));
+        ctx.new_v1(Timestamp::now().to_gregorian(), node)
+    }
+
+    /// Create a new Version 6 UUID using the current system time and
+    /// provided node.
+    ///
+    /// The clock sequence is tracked by a process-wide [`TimeContext`],
+    /// shared with [`Uuid::now_v1`].
+    ///
+    /// This requires the `std` and `getrandom` features.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// # let PSEUDO = [0; 6];
+    /// let uuid = Uuid::now_v6(PSEUDO);
+    /// ```
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "getrandom"))))]
+    pub fn now_v6(node: [u8; 6]) -> Self {
+        let mut ctx = time_context();
+        let ctx = ctx.get_or_insert_with(|| TimeContext::new(Rng::new()));
+        ctx.new_v6(Timestamp::now().to_gregorian(), node)
+    }
+
+    /// Create a new Version 7 UUID using the current system time.
+    ///
+    /// UUIDs created by this method within the same process are guaranteed to
+    /// be in creation order, using a process-wide [`V7Generator`].
+    ///
+    /// This requires the `std` and `getrandom` features.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let first = Uuid::now_v7();
+    /// let second = Uuid::now_v7();
+    /// assert!(first < second);
+    /// ```
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "getrandom"))))]
+    pub fn now_v7() -> Self {
+        use std::sync::{Mutex, PoisonError};
+        static GENERATOR: Mutex<Option<V7Generator>> = Mutex::new(None);
+
+        let now = Timestamp::now();
+        let mut gen = GENERATOR.lock().unwrap_or_else(PoisonError::into_inner);
+        gen.get_or_insert_with(|| V7Generator::new(Rng::new()))
+            .generate(now.to_unix_millis())
+    }
+}
+
+/// The process-wide [`TimeContext`] used by [`Uuid::now_v1`] and
+/// [`Uuid::now_v6`].
+#[cfg(all(feature = "std", feature = "getrandom"))]
+fn time_context() -> std::sync::MutexGuard<'static, Option<TimeContext>> {
+    use std::sync::{Mutex, PoisonError};
+    static CONTEXT: Mutex<Option<TimeContext>> = Mutex::new(None);
+
+    CONTEXT.lock().unwrap_or_else(PoisonError::into_inner)
 }
 
 /// See [`Uuid::parse`] for details.
@@ -912,6 +1917,27 @@ impl FromStr for Uuid {
     }
 }
 
+/// Parse a [`Uuid`] from ASCII text, see [`Uuid::parse_ascii`] for details.
+///
+/// This does not convert 16 raw bytes, use [`Uuid::from_bytes`] for that.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Uuid;
+/// # use std::convert::TryFrom;
+/// let uuid = Uuid::try_from(&b"662aa7c7-7598-4d56-8bcc-a72c30f998a2"[..]).unwrap();
+/// ```
+impl TryFrom<&[u8]> for Uuid {
+    type Error = ParseUuidError;
+
+    /// See [`Uuid::parse_ascii`] for details.
+    #[inline]
+    fn try_from(s: &[u8]) -> Result<Self, Self::Error> {
+        Uuid::parse_ascii(s)
+    }
+}
+
 /// Display the [`Uuid`] in uppercase hex.
 ///
 /// # Example
@@ -1064,9 +2090,8 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "experimental_uuid")]
     fn new_v6() {
-        // Values sourced from https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#name-test-vectors
+        // Values sourced from https://www.rfc-editor.org/rfc/rfc9562#name-example-of-a-uuidv6-value
         const UUID: &str = "1EC9414C-232A-6B00-B3C8-9E6BDECED846";
         let (ticks, counter, node) = (138648505420000000, 13256, [158, 107, 222, 206, 216, 70]);
 
@@ -1078,14 +2103,21 @@ mod tests {
         assert_eq!(uuid.variant(), Variant::Rfc4122);
 
         assert_eq!(uuid.timestamp(), uuid_.timestamp());
+        assert_eq!(uuid.timestamp(), Some(UuidTimestamp::Gregorian(ticks)));
         assert_eq!(uuid.clock_sequence(), uuid_.clock_sequence());
         assert_eq!(uuid.node()[..], uuid_.node());
+
+        use uuid_::{v1::Timestamp, Uuid as Uuid_};
+        let (ticks, counter, node) = (138788330336896890u64, 8648, *b"world!");
+        let uuid = Uuid::new_v6(ticks, counter, node);
+        let uuid_ = Uuid_::new_v6(Timestamp::from_gregorian_time(ticks, counter), &node);
+        assert_eq!(uuid.to_bytes(), *uuid_.as_bytes());
+        assert_eq!(uuid.timestamp(), Some(UuidTimestamp::Gregorian(ticks)));
     }
 
     #[test]
-    #[cfg(feature = "experimental_uuid")]
     fn new_v7() {
-        // Values sourced from https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#name-uuidv7-example-test-vector
+        // Values sourced from https://www.rfc-editor.org/rfc/rfc9562#name-example-of-a-uuidv7-value
         const UUID: &str = "017F22E2-79B0-7CC3-98C4-DC0C0C07398F";
         let (unix_ts, rand_a, rand_b) = (0x17F22E279B0, 0xCC3, 0x18C4DC0C0C07398F);
 
@@ -1097,32 +2129,77 @@ mod tests {
         assert_eq!(uuid.variant(), Variant::Rfc4122);
 
         assert_eq!(uuid.timestamp(), uuid_.timestamp());
+        assert_eq!(uuid.timestamp(), Some(UuidTimestamp::UnixMillis(unix_ts)));
         assert_eq!(uuid.clock_sequence(), uuid_.clock_sequence());
         assert_eq!(uuid.node()[..], uuid_.node());
     }
 
+    #[test]
+    fn fill() {
+        let mut uuids = [Uuid::nil(); 64];
+        Uuid::fill_v4(&mut Rng::from_seed([0; 32]), &mut uuids);
+        let mut rng = Rng::from_seed([0; 32]);
+        for uuid in uuids {
+            assert_eq!(uuid, Uuid::new_v4_rng(&mut rng));
+        }
+
+        Uuid::fill_v7(&mut Rng::from_seed([0; 32]), 0x17F22E279B0, &mut uuids);
+        for uuid in uuids {
+            assert_eq!(uuid.version(), Version::UnixTime);
+            assert_eq!(uuid.variant(), Variant::Rfc4122);
+            assert_eq!(
+                uuid.timestamp(),
+                Some(UuidTimestamp::UnixMillis(0x17F22E279B0))
+            );
+        }
+        assert_ne!(uuids[0], uuids[1]);
+    }
+
     #[test]
     fn time() {
         use uuid_::{v1::*, Uuid as Uuid_};
         let (ticks, counter, node) = (138788330336896890u64, 8648, *b"world!");
 
         let uuid = Uuid::new_v1(ticks, counter, node);
-        let uuid_ = Uuid_::new_v1(Timestamp::from_rfc4122(ticks, counter), &node);
+        let uuid_ = Uuid_::new_v1(Timestamp::from_gregorian_time(ticks, counter), &node);
         assert_eq!(uuid.to_bytes(), *uuid_.as_bytes());
         assert_eq!(uuid.version(), Version::Time);
         assert_eq!(uuid.variant(), Variant::Rfc4122);
 
         assert_eq!(
             uuid.timestamp(),
-            uuid_.get_timestamp().unwrap().to_rfc4122().0
+            Some(UuidTimestamp::Gregorian(
+                uuid_.get_timestamp().unwrap().to_gregorian().0
+            ))
         );
         assert_eq!(
             uuid.clock_sequence(),
-            uuid_.get_timestamp().unwrap().to_rfc4122().1
+            uuid_.get_timestamp().unwrap().to_gregorian().1
         );
         assert_eq!(uuid.node()[..], uuid_.as_fields().3[2..]);
     }
 
+    #[test]
+    fn dce() {
+        let (ticks, node) = (138788330336896890u64, *b"world!");
+        for domain in [Domain::Person, Domain::Group, Domain::Org] {
+            let uuid = Uuid::new_v2(domain, 0xDEADBEEF, ticks, 0x2A, node);
+            assert_eq!(uuid.version(), Version::Dce);
+            assert_eq!(uuid.variant(), Variant::Rfc4122);
+            assert_eq!(uuid.local_id(), Some(0xDEADBEEF));
+            assert_eq!(uuid.domain(), Some(domain));
+            assert_eq!(
+                uuid.timestamp(),
+                Some(UuidTimestamp::Gregorian(ticks & !0xFFFF_FFFF))
+            );
+            assert_eq!(uuid.node(), node);
+        }
+
+        let uuid = Uuid::new_v1(ticks, 0, node);
+        assert_eq!(uuid.local_id(), None);
+        assert_eq!(uuid.domain(), None);
+    }
+
     #[test]
     fn md5() {
         name(Uuid::new_v3, Version::Md5);
@@ -1140,6 +2217,38 @@ mod tests {
         name(Uuid::new_v5, Version::Sha1)
     }
 
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn sha2() {
+        name(Uuid::new_v8_sha256, Version::Custom);
+        name(Uuid::new_v8_sha512, Version::Custom);
+        // From RFC 9562 Appendix B.2
+        assert_eq!(
+            Uuid::new_v8_sha256(NAMESPACE_DNS, b"www.example.com"),
+            Uuid::from_str("5c146b14-3c52-8afd-938a-375d0df1fbf6").unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "hmac")]
+    fn hmac() {
+        name(
+            |ns, name| Uuid::new_v8_hmac_sha256(b"key", ns, name),
+            Version::Custom,
+        );
+        let uuid = Uuid::new_v8_hmac_sha256(b"key", NAMESPACE_DNS, b"www.example.com");
+        assert_eq!(
+            uuid,
+            // Computed independently with Python's hmac module
+            Uuid::from_str("f356dd28-7284-8000-bc35-0da81135490d").unwrap()
+        );
+        assert_ne!(
+            uuid,
+            Uuid::new_v8_hmac_sha256(b"other", NAMESPACE_DNS, b"www.example.com")
+        );
+        assert_ne!(uuid, Uuid::new_v8_sha256(NAMESPACE_DNS, b"www.example.com"));
+    }
+
     #[test]
     fn parse_string() {
         let test = &[UUID_V4, UUID_V4_URN, UUID_V4_BRACED, UUID_V4_SIMPLE];
@@ -1158,6 +2267,130 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_error() {
+        let kind = |s: &str| Uuid::parse(s).unwrap_err().kind();
+
+        assert_eq!(kind(""), ParseErrorKind::InvalidLength { len: 0 });
+        assert_eq!(
+            kind(&UUID_V4[1..]),
+            ParseErrorKind::InvalidLength { len: 35 }
+        );
+        assert_eq!(
+            kind("662aa7c7-7598-4d56-8bcc-a72c30f998é"),
+            ParseErrorKind::NonAscii { index: 34 }
+        );
+        assert_eq!(
+            kind("662aa7c7-7598-4d56-8bcc-a72c30fz98a2"),
+            ParseErrorKind::InvalidCharacter {
+                found: 'z',
+                index: 31
+            }
+        );
+        assert_eq!(
+            kind("urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30fz98a2"),
+            ParseErrorKind::InvalidCharacter {
+                found: 'z',
+                index: 40
+            }
+        );
+        assert_eq!(
+            kind("+62aa7c775984d568bcca72c30f998a2"),
+            ParseErrorKind::InvalidCharacter {
+                found: '+',
+                index: 0
+            }
+        );
+        assert_eq!(
+            kind("662aa7c7-7598-4d56-8bcc-a72c30f9-8a2"),
+            ParseErrorKind::MisplacedHyphen { index: 32 }
+        );
+        assert_eq!(
+            Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30fz98a2")
+                .unwrap_err()
+                .to_string(),
+            "invalid character 'z' at index 31, expected a hexadecimal digit"
+        );
+    }
+
+    #[test]
+    fn parse_strict() {
+        let kind = |s: &str| Uuid::parse(s).unwrap_err().kind();
+        let test = &[
+            (UUID_V4, Format::Hyphenated),
+            (UUID_V4_URN, Format::Urn),
+            (UUID_V4_URN_UPPER, Format::Urn),
+            (UUID_V4_BRACED, Format::Braced),
+            (UUID_V4_SIMPLE, Format::Simple),
+        ];
+        for (uuid, format) in test {
+            let (uuid, f) = Uuid::parse_with_format(uuid).unwrap();
+            assert_eq!(RAW, uuid.to_bytes(), "Parsed UUID bytes don't match");
+            assert_eq!(*format, f);
+        }
+
+        let bad = &[
+            "xxxxxxxxx662aa7c7-7598-4d56-8bcc-a72c30f998a2",
+            "(662aa7c7-7598-4d56-8bcc-a72c30f998a2]",
+            "662aa7c7x7598-4d56-8bcc-a72c30f998a2",
+        ];
+        assert_eq!(kind(bad[0]), ParseErrorKind::InvalidUrnPrefix);
+        assert_eq!(kind(bad[1]), ParseErrorKind::UnbalancedBraces);
+        assert_eq!(
+            kind(bad[2]),
+            ParseErrorKind::MissingHyphen {
+                found: 'x',
+                index: 8
+            }
+        );
+        assert_eq!(
+            kind("662aa7c-77598-4d56-8bcc-a72c30f998a2"),
+            ParseErrorKind::MisplacedHyphen { index: 7 }
+        );
+        for uuid in bad {
+            assert_eq!(RAW, Uuid::parse_lenient(uuid).unwrap().to_bytes());
+        }
+    }
+
+    #[test]
+    fn parse_const() {
+        const UUID: Uuid = uuid!("662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+        assert_eq!(RAW, UUID.to_bytes());
+
+        let good = &[UUID_V4, UUID_V4_URN, UUID_V4_BRACED, UUID_V4_SIMPLE];
+        let bad = &[
+            "",
+            "xxxxxxxxx662aa7c7-7598-4d56-8bcc-a72c30f998a2",
+            "(662aa7c7-7598-4d56-8bcc-a72c30f998a2]",
+            "662aa7c7x7598-4d56-8bcc-a72c30f998a2",
+            "662aa7c-77598-4d56-8bcc-a72c30f998a2",
+            "662aa7c7-7598-4d56-8bcc-a72c30fz98a2",
+            "+62aa7c775984d568bcca72c30f998a2",
+            "662aa7c7-7598-4d56-8bcc-a72c30f998é",
+        ];
+        for uuid in good.iter().chain(bad) {
+            assert_eq!(Uuid::parse_const(uuid), Uuid::parse(uuid));
+            let upper = uuid.to_ascii_uppercase();
+            assert_eq!(Uuid::parse_const(&upper), Uuid::parse(&upper));
+        }
+    }
+
+    #[test]
+    fn parse_ascii() {
+        let test = &[UUID_V4, UUID_V4_URN, UUID_V4_BRACED, UUID_V4_SIMPLE];
+        for uuid in test {
+            assert_eq!(RAW, Uuid::parse_ascii(uuid.as_bytes()).unwrap().to_bytes());
+            assert_eq!(RAW, Uuid::try_from(uuid.as_bytes()).unwrap().to_bytes());
+        }
+
+        let mut invalid = *b"662aa7c7-7598-4d56-8bcc-a72c30f998a2";
+        invalid[33] = 0xFF;
+        assert_eq!(
+            Uuid::parse_ascii(&invalid).unwrap_err().kind(),
+            ParseErrorKind::NonAscii { index: 33 }
+        );
+    }
+
     #[test]
     fn string() {
         let uuid = Uuid::from_bytes(RAW);
@@ -1222,6 +2455,46 @@ mod tests {
         assert_ne!(uuid.to_str(&mut [0; 36]), UUID);
     }
 
+    #[test]
+    fn fields() {
+        const UUID: Uuid = Uuid::from_fields(
+            0x662aa7c7,
+            0x7598,
+            0x4d56,
+            [0x8b, 0xcc, 0xa7, 0x2c, 0x30, 0xf9, 0x98, 0xa2],
+        );
+        assert_eq!(UUID, Uuid::from_bytes(RAW));
+
+        let (d1, d2, d3, d4) = UUID.to_fields();
+        assert_eq!(Uuid::from_fields(d1, d2, d3, d4), UUID);
+        let (d1, d2, d3, d4) = UUID.to_fields_le();
+        assert_eq!(Uuid::from_fields_le(d1, d2, d3, d4), UUID);
+        assert_eq!(
+            Uuid::from_fields(d1, d2, d3, d4).to_bytes(),
+            UUID.to_bytes_me()
+        );
+
+        assert_eq!(Uuid::from_u128(UUID.as_u128()), UUID);
+        assert_eq!(UUID.as_u128(), u128::from_be_bytes(RAW));
+        let (high, low) = UUID.as_u64_pair();
+        assert_eq!((high, low), (0x662aa7c775984d56, 0x8bcca72c30f998a2));
+        assert_eq!(Uuid::from_u64_pair(high, low), UUID);
+        assert_eq!(Uuid::from_u128(u128::MAX), Uuid::max());
+    }
+
+    #[test]
+    fn special() {
+        assert!(Uuid::nil().is_nil());
+        assert!(Uuid::max().is_max());
+        assert!(!Uuid::from_bytes(RAW).is_max());
+        assert_eq!(
+            Uuid::max().to_str(&mut [0; 36]),
+            "ffffffff-ffff-ffff-ffff-ffffffffffff"
+        );
+        assert_eq!(Uuid::max().version(), Version::Reserved);
+        assert_eq!(Uuid::max().variant(), Variant::Reserved);
+    }
+
     #[test]
     fn info() {
         let uuid = Uuid::from_bytes(RAW);
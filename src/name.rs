@@ -0,0 +1,242 @@
+//! Name based UUIDs
+use core::convert::TryInto;
+
+#[cfg(feature = "hmac")]
+use hmac::{Hmac, Mac};
+use md5::{Digest, Md5};
+use sha1::Sha1;
+#[cfg(feature = "sha2")]
+use sha2::{Sha256, Sha512};
+
+use crate::{Uuid, Variant, Version};
+
+/// Hashers for name based UUIDs
+#[derive(Clone)]
+enum NameHasher {
+    Md5(Md5),
+    Sha1(Sha1),
+    #[cfg(feature = "sha2")]
+    Sha256(Sha256),
+    #[cfg(feature = "sha2")]
+    Sha512(Sha512),
+    #[cfg(feature = "hmac")]
+    HmacSha256(Hmac<Sha256>),
+}
+
+/// Incrementally create a name based UUID
+///
+/// This is useful when the name is too large to have in memory at once,
+/// or is split across several buffers.
+///
+/// Under the `std` feature, this also implements `std::io::Write`,
+/// so names can be copied from any reader.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{NameBasedBuilder, NAMESPACE_DNS, Uuid};
+/// let uuid = NameBasedBuilder::v5(NAMESPACE_DNS)
+///     .update(b"example")
+///     .update(b".com")
+///     .finish();
+/// assert_eq!(uuid, Uuid::new_v5(NAMESPACE_DNS, b"example.com"));
+/// ```
+#[derive(Clone)]
+pub struct NameBasedBuilder {
+    hasher: NameHasher,
+}
+
+impl NameBasedBuilder {
+    /// Start a Version 3 UUID in `namespace`, see [`Uuid::new_v3`].
+    #[inline]
+    pub fn v3(namespace: Uuid) -> Self {
+        let mut hasher = Md5::new();
+        hasher.update(namespace.to_bytes());
+        Self {
+            hasher: NameHasher::Md5(hasher),
+        }
+    }
+
+    /// Start a Version 5 UUID in `namespace`, see [`Uuid::new_v5`].
+    #[inline]
+    pub fn v5(namespace: Uuid) -> Self {
+        let mut hasher = Sha1::new();
+        hasher.update(namespace.to_bytes());
+        Self {
+            hasher: NameHasher::Sha1(hasher),
+        }
+    }
+
+    /// Start a SHA-256 Version 8 UUID in `namespace`,
+    /// see [`Uuid::new_v8_sha256`].
+    #[cfg(feature = "sha2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha2")))]
+    #[inline]
+    pub fn v8_sha256(namespace: Uuid) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(namespace.to_bytes());
+        Self {
+            hasher: NameHasher::Sha256(hasher),
+        }
+    }
+
+    /// Start a SHA-512 Version 8 UUID in `namespace`,
+    /// see [`Uuid::new_v8_sha512`].
+    #[cfg(feature = "sha2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha2")))]
+    #[inline]
+    pub fn v8_sha512(namespace: Uuid) -> Self {
+        let mut hasher = Sha512::new();
+        hasher.update(namespace.to_bytes());
+        Self {
+            hasher: NameHasher::Sha512(hasher),
+        }
+    }
+
+    /// Start a keyed HMAC-SHA-256 Version 8 UUID in `namespace`,
+    /// see [`Uuid::new_v8_hmac_sha256`].
+    #[cfg(feature = "hmac")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+    #[inline]
+    pub fn v8_hmac_sha256(key: &[u8], namespace: Uuid) -> Self {
+        // HMAC accepts keys of any length
+        let mut hasher = Hmac::<Sha256>::new_from_slice(key).unwrap();
+        Mac::update(&mut hasher, &namespace.to_bytes());
+        Self {
+            hasher: NameHasher::HmacSha256(hasher),
+        }
+    }
+
+    /// Add `data` to the name
+    #[inline]
+    pub fn update(mut self, data: &[u8]) -> Self {
+        self.write(data);
+        self
+    }
+
+    /// Create the UUID from the name
+    #[inline]
+    pub fn finish(self) -> Uuid {
+        let (mut uuid, version) = match self.hasher {
+            NameHasher::Md5(hasher) => (Uuid::from_bytes(hasher.finalize().into()), Version::Md5),
+            NameHasher::Sha1(hasher) => (
+                Uuid::from_bytes(hasher.finalize()[..16].try_into().unwrap()),
+                Version::Sha1,
+            ),
+            #[cfg(feature = "sha2")]
+            NameHasher::Sha256(hasher) => (
+                Uuid::from_bytes(hasher.finalize()[..16].try_into().unwrap()),
+                Version::Custom,
+            ),
+            #[cfg(feature = "sha2")]
+            NameHasher::Sha512(hasher) => (
+                Uuid::from_bytes(hasher.finalize()[..16].try_into().unwrap()),
+                Version::Custom,
+            ),
+            #[cfg(feature = "hmac")]
+            NameHasher::HmacSha256(hasher) => (
+                Uuid::from_bytes(hasher.finalize().into_bytes()[..16].try_into().unwrap()),
+                Version::Custom,
+            ),
+        };
+        uuid.set_version(version);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid
+    }
+
+    #[inline]
+    fn write(&mut self, data: &[u8]) {
+        match &mut self.hasher {
+            NameHasher::Md5(hasher) => hasher.update(data),
+            NameHasher::Sha1(hasher) => hasher.update(data),
+            #[cfg(feature = "sha2")]
+            NameHasher::Sha256(hasher) => hasher.update(data),
+            #[cfg(feature = "sha2")]
+            NameHasher::Sha512(hasher) => hasher.update(data),
+            #[cfg(feature = "hmac")]
+            NameHasher::HmacSha256(hasher) => Mac::update(hasher, data),
+        }
+    }
+}
+
+impl core::fmt::Debug for NameBasedBuilder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let hash = match self.hasher {
+            NameHasher::Md5(_) => "MD5",
+            NameHasher::Sha1(_) => "SHA-1",
+            #[cfg(feature = "sha2")]
+            NameHasher::Sha256(_) => "SHA-256",
+            #[cfg(feature = "sha2")]
+            NameHasher::Sha512(_) => "SHA-512",
+            #[cfg(feature = "hmac")]
+            NameHasher::HmacSha256(_) => "HMAC-SHA-256",
+        };
+        f.debug_struct("NameBasedBuilder")
+            .field("hash", &hash)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::io::Write for NameBasedBuilder {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        NameBasedBuilder::write(self, buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NAMESPACE_DNS;
+
+    #[test]
+    fn streaming() {
+        let name = b"www.widgets.com";
+        for (builder, expected) in [
+            (
+                NameBasedBuilder::v3(NAMESPACE_DNS),
+                Uuid::new_v3(NAMESPACE_DNS, name),
+            ),
+            (
+                NameBasedBuilder::v5(NAMESPACE_DNS),
+                Uuid::new_v5(NAMESPACE_DNS, name),
+            ),
+            #[cfg(feature = "sha2")]
+            (
+                NameBasedBuilder::v8_sha256(NAMESPACE_DNS),
+                Uuid::new_v8_sha256(NAMESPACE_DNS, name),
+            ),
+            #[cfg(feature = "sha2")]
+            (
+                NameBasedBuilder::v8_sha512(NAMESPACE_DNS),
+                Uuid::new_v8_sha512(NAMESPACE_DNS, name),
+            ),
+            #[cfg(feature = "hmac")]
+            (
+                NameBasedBuilder::v8_hmac_sha256(b"secret", NAMESPACE_DNS),
+                Uuid::new_v8_hmac_sha256(b"secret", NAMESPACE_DNS, name),
+            ),
+        ] {
+            let uuid = name
+                .chunks(4)
+                .fold(builder.clone(), NameBasedBuilder::update)
+                .finish();
+            assert_eq!(uuid, expected);
+
+            #[cfg(feature = "std")]
+            {
+                let mut builder = builder;
+                std::io::copy(&mut &name[..], &mut builder).unwrap();
+                assert_eq!(builder.finish(), expected);
+            }
+        }
+    }
+}
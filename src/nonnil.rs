@@ -0,0 +1,135 @@
+//! UUIDs guaranteed not to be nil
+use core::{convert::TryFrom, num::NonZeroU128};
+
+use crate::{Bytes, ParseErrorKind, ParseUuidError, Uuid};
+
+/// A [`Uuid`] that is guaranteed not to be [`Uuid::nil`]
+///
+/// This is 16 bytes like [`Uuid`], but the nil UUID is used as a niche,
+/// so `Option<NonNilUuid>` is also 16 bytes.
+///
+/// # Alignment
+///
+/// Unlike [`Uuid`], which has an alignment of 1, this is backed by
+/// [`NonZeroU128`] and has its alignment, 16 on most platforms.
+/// This can add padding when stored next to other fields,
+/// for example `(NonNilUuid, u32)` is 32 bytes where `(Uuid, u32)` is 20,
+/// so prefer it where the niche matters, such as large collections of
+/// `Option<NonNilUuid>`.
+///
+/// Parsing, formatting, and ordering, and serde when enabled,
+/// behave exactly as they do for [`Uuid`], except that the nil UUID is
+/// rejected.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{NonNilUuid, Uuid};
+/// # use std::convert::TryFrom;
+/// assert_eq!(core::mem::size_of::<Option<NonNilUuid>>(), 16);
+///
+/// let uuid: NonNilUuid = "662aa7c7-7598-4d56-8bcc-a72c30f998a2".parse().unwrap();
+/// assert_eq!(NonNilUuid::new(uuid.get()), Some(uuid));
+///
+/// assert_eq!(NonNilUuid::new(Uuid::nil()), None);
+/// NonNilUuid::try_from(Uuid::nil()).unwrap_err();
+/// ```
+// Stored big-endian, so the derived `Ord` matches `Uuid`.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct NonNilUuid(NonZeroU128);
+
+impl NonNilUuid {
+    /// Create a `NonNilUuid` from `uuid`, or `None` if it is nil.
+    #[inline]
+    pub const fn new(uuid: Uuid) -> Option<Self> {
+        match NonZeroU128::new(u128::from_be_bytes(uuid.0)) {
+            Some(n) => Some(Self(n)),
+            None => None,
+        }
+    }
+
+    /// Create a `NonNilUuid` from `uuid`, without checking if it is nil.
+    ///
+    /// # Safety
+    ///
+    /// `uuid` must not be [`Uuid::nil`].
+    #[inline]
+    pub const unsafe fn new_unchecked(uuid: Uuid) -> Self {
+        Self(NonZeroU128::new_unchecked(u128::from_be_bytes(uuid.0)))
+    }
+
+    /// The UUID
+    #[inline]
+    pub const fn get(self) -> Uuid {
+        Uuid::from_bytes(self.0.get().to_be_bytes())
+    }
+
+    /// The UUID as bytes, see [`Uuid::to_bytes`].
+    #[inline]
+    pub const fn to_bytes(self) -> Bytes {
+        self.0.get().to_be_bytes()
+    }
+
+    /// Parse a UUID, see [`Uuid::parse`] for details.
+    ///
+    /// # Errors
+    ///
+    /// If `s` is not a valid UUID, or is nil.
+    #[inline]
+    pub fn parse(s: &str) -> Result<Self, ParseUuidError> {
+        Self::try_from(Uuid::parse(s)?)
+    }
+}
+
+/// Fails with [`ParseErrorKind::Nil`] if `uuid` is nil.
+impl TryFrom<Uuid> for NonNilUuid {
+    type Error = ParseUuidError;
+
+    #[inline]
+    fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+        Self::new(uuid).ok_or(ParseUuidError::new(ParseErrorKind::Nil))
+    }
+}
+
+uuid_wrapper!(impl[] NonNilUuid, checked[], |u| u.get());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_nil() {
+        assert_eq!(core::mem::size_of::<NonNilUuid>(), 16);
+        assert_eq!(core::mem::size_of::<Option<NonNilUuid>>(), 16);
+        assert_eq!(
+            core::mem::align_of::<NonNilUuid>(),
+            core::mem::align_of::<NonZeroU128>()
+        );
+
+        let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+        let non_nil = NonNilUuid::new(uuid).unwrap();
+        assert_eq!(non_nil.get(), uuid);
+        assert_eq!(non_nil.to_bytes(), uuid.to_bytes());
+        assert_eq!(format!("{}", non_nil), format!("{}", uuid));
+        assert_eq!(format!("{:x}", non_nil), format!("{:x}", uuid));
+        assert_eq!(NonNilUuid::parse(&uuid.to_string()), Ok(non_nil));
+
+        // Ordering matches `Uuid`
+        let max = NonNilUuid::new(Uuid::max()).unwrap();
+        let low = NonNilUuid::new(Uuid::from_bytes([1; 16])).unwrap();
+        let high = NonNilUuid::new(Uuid::from_bytes([
+            0xFF, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ]))
+        .unwrap();
+        assert!(low < non_nil && non_nil < high && high < max);
+
+        assert_eq!(NonNilUuid::new(Uuid::nil()), None);
+        assert_eq!(
+            NonNilUuid::parse("00000000-0000-0000-0000-000000000000")
+                .unwrap_err()
+                .kind(),
+            ParseErrorKind::Nil
+        );
+    }
+}
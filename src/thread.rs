@@ -0,0 +1,167 @@
+//! Thread-local random number generator
+use core::{
+    cell::RefCell,
+    marker::PhantomData,
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+use rand_chacha::{
+    rand_core::{CryptoRng, Error, OsRng, RngCore, SeedableRng},
+    ChaChaRng,
+};
+
+/// Number of bytes generated before reseeding from the OS
+const RESEED_THRESHOLD: usize = 64 * 1024;
+
+/// Number of times this process has been forked from its parent,
+/// incremented in the child.
+static FORK_GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+/// Register a `fork` handler to increment [`FORK_GENERATION`], once.
+fn register_fork_handler() {
+    #[cfg(unix)]
+    {
+        static REGISTER: std::sync::Once = std::sync::Once::new();
+
+        extern "C" fn child() {
+            FORK_GENERATION.fetch_add(1, Relaxed);
+        }
+
+        // Safety: `child` is async-signal-safe, it only touches an atomic.
+        REGISTER.call_once(|| unsafe {
+            libc::pthread_atfork(None, None, Some(child));
+        });
+    }
+}
+
+/// Per-thread generator state
+struct ThreadState {
+    rng: ChaChaRng,
+
+    /// [`FORK_GENERATION`] when last seeded
+    generation: usize,
+
+    /// Bytes left until the next reseed
+    remaining: usize,
+}
+
+impl ThreadState {
+    fn new() -> Self {
+        register_fork_handler();
+        Self {
+            rng: ChaChaRng::from_rng(OsRng).unwrap(),
+            generation: FORK_GENERATION.load(Relaxed),
+            remaining: RESEED_THRESHOLD,
+        }
+    }
+
+    /// Fill `dest`, reseeding first if needed.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // A different generation means we are a forked child,
+        // which must not repeat the parent's output.
+        // This is only an atomic load, unlike checking the process ID.
+        if self.remaining < dest.len() || self.generation != FORK_GENERATION.load(Relaxed) {
+            *self = Self::new();
+        }
+        self.remaining = self.remaining.saturating_sub(dest.len());
+        self.rng.fill_bytes(dest);
+    }
+}
+
+std::thread_local! {
+    static STATE: RefCell<ThreadState> = RefCell::new(ThreadState::new());
+}
+
+/// A handle to a lazily initialized thread-local CSPRNG
+///
+/// Each thread has its own ChaCha generator, seeded from the OS.
+/// It is reseeded after every 64 KiB of output, and in the child after
+/// `fork`, so forked processes don't create the same UUIDs.
+///
+/// This is used by [`Uuid::new_v4`][crate::Uuid::new_v4].
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{ThreadRng, Uuid};
+/// let uuid = Uuid::new_v4_rng(&mut ThreadRng::new());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ThreadRng {
+    /// Not `Send` or `Sync`, like the thread-local it refers to
+    _thread: PhantomData<*const ()>,
+}
+
+impl ThreadRng {
+    /// Get a handle to the current thread's generator
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RngCore for ThreadRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        STATE.with(|state| state.borrow_mut().fill_bytes(dest))
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for ThreadRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reseed() {
+        let mut rng = ThreadRng::new();
+        let mut buf = [0; 16];
+        rng.fill_bytes(&mut buf);
+        assert_ne!(buf, [0; 16]);
+
+        let seed = STATE.with(|s| s.borrow().rng.get_seed());
+        rng.fill_bytes(&mut [0; RESEED_THRESHOLD]);
+        assert_ne!(
+            STATE.with(|s| s.borrow().rng.get_seed()),
+            seed,
+            "Must reseed after the threshold"
+        );
+
+        // Pretend we were forked
+        FORK_GENERATION.fetch_add(1, Relaxed);
+        let seed = STATE.with(|s| s.borrow().rng.get_seed());
+        rng.next_u32();
+        assert_ne!(
+            STATE.with(|s| s.borrow().rng.get_seed()),
+            seed,
+            "Must reseed after a fork"
+        );
+
+        let other = std::thread::spawn(|| STATE.with(|s| s.borrow().rng.get_seed()));
+        assert_ne!(
+            other.join().unwrap(),
+            STATE.with(|s| s.borrow().rng.get_seed())
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! Timestamps for time based UUIDs
+use core::{fmt, time::Duration};
+
+/// Number of 100ns ticks between the Gregorian epoch, 1582-10-15,
+/// and the UNIX epoch, 1970-01-01.
+const GREGORIAN_OFFSET: u64 = 0x01B2_1DD2_1381_4000;
+
+/// Number of 100ns ticks in a second
+const TICKS_PER_SEC: u64 = 10_000_000;
+
+/// Number of 100ns ticks in a millisecond
+const TICKS_PER_MILLI: u64 = 10_000;
+
+/// A point in time, for creating time based UUIDs
+///
+/// This is stored as the number of 100 nanosecond intervals since the
+/// Gregorian epoch, 1582-10-15, as used by Version 1 and 6 UUIDs, and can be
+/// converted to and from the UNIX epoch, as used by Version 7 UUIDs.
+///
+/// Times that can't be represented saturate to the nearest representable
+/// time.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Timestamp;
+/// # use std::time::Duration;
+/// let time = Timestamp::from_unix(Duration::from_millis(0x17F22E279B0));
+/// assert_eq!(time.to_unix_millis(), 0x17F22E279B0);
+/// assert_eq!(time.to_gregorian(), 138648505420000000);
+/// ```
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub struct Timestamp(u64);
+
+impl Timestamp {
+    /// Create a timestamp from the number of 100 nanosecond intervals since
+    /// the Gregorian epoch, 1582-10-15.
+    #[inline]
+    pub const fn from_gregorian(ticks: u64) -> Self {
+        Self(ticks)
+    }
+
+    /// Create a timestamp from the time since the UNIX epoch, 1970-01-01.
+    ///
+    /// Precision beyond 100 nanoseconds is truncated.
+    #[inline]
+    pub const fn from_unix(time: Duration) -> Self {
+        let ticks = time
+            .as_secs()
+            .saturating_mul(TICKS_PER_SEC)
+            .saturating_add(time.subsec_nanos() as u64 / 100);
+        Self(ticks.saturating_add(GREGORIAN_OFFSET))
+    }
+
+    /// Create a timestamp from the number of milliseconds since the UNIX
+    /// epoch, 1970-01-01.
+    #[inline]
+    pub const fn from_unix_millis(millis: u64) -> Self {
+        Self(
+            millis
+                .saturating_mul(TICKS_PER_MILLI)
+                .saturating_add(GREGORIAN_OFFSET),
+        )
+    }
+
+    /// The current system time
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn now() -> Self {
+        std::time::SystemTime::now().into()
+    }
+
+    /// The number of 100 nanosecond intervals since the Gregorian epoch,
+    /// 1582-10-15.
+    ///
+    /// This is the timestamp used by [`Uuid::new_v1`][crate::Uuid::new_v1].
+    #[inline]
+    pub const fn to_gregorian(self) -> u64 {
+        self.0
+    }
+
+    /// The time since the UNIX epoch, 1970-01-01.
+    ///
+    /// Times before the UNIX epoch are zero.
+    #[inline]
+    pub const fn to_unix(self) -> Duration {
+        let ticks = self.0.saturating_sub(GREGORIAN_OFFSET);
+        Duration::new(ticks / TICKS_PER_SEC, (ticks % TICKS_PER_SEC) as u32 * 100)
+    }
+
+    /// The number of milliseconds since the UNIX epoch, 1970-01-01.
+    ///
+    /// Times before the UNIX epoch are zero.
+    ///
+    /// This is the timestamp used by `Uuid::new_v7`.
+    #[inline]
+    pub const fn to_unix_millis(self) -> u64 {
+        self.0.saturating_sub(GREGORIAN_OFFSET) / TICKS_PER_MILLI
+    }
+
+    /// The sub-millisecond part of the time since the UNIX epoch,
+    /// in nanoseconds.
+    #[inline]
+    pub const fn submillisecond_nanos(self) -> u32 {
+        (self.0.saturating_sub(GREGORIAN_OFFSET) % TICKS_PER_MILLI) as u32 * 100
+    }
+}
+
+/// Error converting a [`Timestamp`] to a time that can't represent it
+///
+/// This happens converting to `SystemTime` for timestamps before the
+/// earliest time the platform supports, such as before 1601 on Windows.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TimestampRangeError(());
+
+impl fmt::Display for TimestampRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "timestamp out of range")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for TimestampRangeError {}
+
+/// A timestamp decoded from a UUID, see [`Uuid::timestamp`][crate::Uuid::timestamp].
+///
+/// The epoch and resolution depend on the UUID version.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum UuidTimestamp {
+    /// The 60-bit number of 100 nanosecond intervals since the Gregorian
+    /// epoch, 1582-10-15.
+    ///
+    /// Used by Version 1 and 6 UUIDs.
+    Gregorian(u64),
+
+    /// The 48-bit number of milliseconds since the UNIX epoch, 1970-01-01.
+    ///
+    /// Used by Version 7 UUIDs.
+    UnixMillis(u64),
+}
+
+impl UuidTimestamp {
+    /// Convert to a [`Timestamp`]
+    #[inline]
+    pub const fn to_timestamp(self) -> Timestamp {
+        match self {
+            UuidTimestamp::Gregorian(ticks) => Timestamp::from_gregorian(ticks),
+            UuidTimestamp::UnixMillis(millis) => Timestamp::from_unix_millis(millis),
+        }
+    }
+}
+
+/// See [`UuidTimestamp::to_timestamp`] for details.
+impl From<UuidTimestamp> for Timestamp {
+    #[inline]
+    fn from(time: UuidTimestamp) -> Self {
+        time.to_timestamp()
+    }
+}
+
+/// See [`Timestamp::from_unix`] for details.
+impl From<Duration> for Timestamp {
+    #[inline]
+    fn from(time: Duration) -> Self {
+        Self::from_unix(time)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<std::time::SystemTime> for Timestamp {
+    fn from(time: std::time::SystemTime) -> Self {
+        match time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(time) => Self::from_unix(time),
+            Err(e) => {
+                let time = e.duration();
+                let ticks = time
+                    .as_secs()
+                    .saturating_mul(TICKS_PER_SEC)
+                    .saturating_add(time.subsec_nanos() as u64 / 100);
+                Self(GREGORIAN_OFFSET.saturating_sub(ticks))
+            }
+        }
+    }
+}
+
+/// Fails with [`TimestampRangeError`] if `time` is outside the range of
+/// `SystemTime` on this platform.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl TryFrom<Timestamp> for std::time::SystemTime {
+    type Error = TimestampRangeError;
+
+    fn try_from(time: Timestamp) -> Result<Self, Self::Error> {
+        let time = match time.0.checked_sub(GREGORIAN_OFFSET) {
+            Some(_) => std::time::UNIX_EPOCH.checked_add(time.to_unix()),
+            None => {
+                let ticks = GREGORIAN_OFFSET - time.0;
+                std::time::UNIX_EPOCH.checked_sub(Duration::new(
+                    ticks / TICKS_PER_SEC,
+                    (ticks % TICKS_PER_SEC) as u32 * 100,
+                ))
+            }
+        };
+        time.ok_or(TimestampRangeError(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn epochs() {
+        // 1582-10-15
+        let time = Timestamp::from_gregorian(0);
+        assert_eq!(time.to_unix(), Duration::ZERO);
+        #[cfg(feature = "std")]
+        {
+            use std::time::{SystemTime, UNIX_EPOCH};
+
+            let system = SystemTime::try_from(time);
+            // Windows can't represent 1582
+            if cfg!(not(windows)) {
+                assert_eq!(system, Ok(UNIX_EPOCH - Duration::from_secs(12219292800)));
+            }
+            if let Ok(system) = system {
+                assert_eq!(Timestamp::from(system), time);
+            }
+            assert_eq!(Timestamp::from(UNIX_EPOCH).to_gregorian(), GREGORIAN_OFFSET);
+        }
+
+        let time = Timestamp::from_unix(Duration::ZERO);
+        assert_eq!(time.to_gregorian(), GREGORIAN_OFFSET);
+        assert_eq!(time.to_unix(), Duration::ZERO);
+
+        let time = Timestamp::from_unix(Duration::new(1_645_557_742, 123_456_789));
+        assert_eq!(time.to_unix(), Duration::new(1_645_557_742, 123_456_700));
+        assert_eq!(time.to_unix_millis(), 1_645_557_742_123);
+        assert_eq!(time.submillisecond_nanos(), 456_700);
+        assert_eq!(
+            Timestamp::from_unix_millis(1_645_557_742_123),
+            Timestamp::from_unix(Duration::from_millis(1_645_557_742_123))
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! UUIDs tagged with a type
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::{versioned::check, ParseUuidError, Uuid, Version};
+
+/// A tag for [`TypedUuid`]
+///
+/// Tags are usually uninhabited types, and only exist to tell UUIDs apart.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{UuidTag, Version};
+/// enum User {}
+/// impl UuidTag for User {}
+///
+/// // Orders must use Version 7 UUIDs
+/// enum Order {}
+/// impl UuidTag for Order {
+///     const VERSION: Option<Version> = Some(Version::UnixTime);
+/// }
+/// ```
+pub trait UuidTag {
+    /// The version every UUID with this tag is required to be, if any.
+    ///
+    /// When set, UUIDs are also required to be the RFC 9562 variant.
+    const VERSION: Option<Version> = None;
+}
+
+/// A [`Uuid`] tagged with the type `T`, so UUIDs for different things can't
+/// be mixed up.
+///
+/// This has the same representation as [`Uuid`], and `T` is never stored.
+/// UUIDs with different tags can't be compared with each other.
+///
+/// Parsing, formatting, ordering, and hashing, and serde when enabled,
+/// behave exactly as they do for [`Uuid`], except that constructing one
+/// checks the version and variant required by [`UuidTag::VERSION`].
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{TypedUuid, Uuid, UuidTag};
+/// enum User {}
+/// impl UuidTag for User {}
+/// type UserId = TypedUuid<User>;
+///
+/// enum Order {}
+/// impl UuidTag for Order {}
+/// type OrderId = TypedUuid<Order>;
+///
+/// let user: UserId = "662aa7c7-7598-4d56-8bcc-a72c30f998a2".parse().unwrap();
+/// let order = OrderId::new(Uuid::new_v4()).unwrap();
+/// assert_eq!(user.to_string(), "662AA7C7-7598-4D56-8BCC-A72C30F998A2");
+/// ```
+///
+/// UUIDs with different tags can't be compared
+///
+/// ```rust,compile_fail
+/// # use nuuid::{TypedUuid, Uuid, UuidTag};
+/// # enum User {}
+/// # impl UuidTag for User {}
+/// # enum Order {}
+/// # impl UuidTag for Order {}
+/// let user = TypedUuid::<User>::new(Uuid::nil()).unwrap();
+/// let order = TypedUuid::<Order>::new(Uuid::nil()).unwrap();
+/// assert_eq!(user, order);
+/// ```
+#[repr(transparent)]
+pub struct TypedUuid<T: ?Sized> {
+    uuid: Uuid,
+    _tag: PhantomData<fn() -> T>,
+}
+
+impl<T: UuidTag + ?Sized> TypedUuid<T> {
+    /// Tag `uuid` with `T`
+    ///
+    /// # Errors
+    ///
+    /// If `T` requires a version and `uuid` is not an RFC 9562 UUID of that
+    /// version, see [`ParseErrorKind::WrongVariant`] and
+    /// [`ParseErrorKind::WrongVersion`].
+    ///
+    /// [`ParseErrorKind::WrongVariant`]: crate::ParseErrorKind::WrongVariant
+    /// [`ParseErrorKind::WrongVersion`]: crate::ParseErrorKind::WrongVersion
+    #[inline]
+    pub fn new(uuid: Uuid) -> Result<Self, ParseUuidError> {
+        match T::VERSION {
+            Some(version) => check(uuid, version).map(Self::new_unchecked),
+            None => Ok(Self::new_unchecked(uuid)),
+        }
+    }
+
+    /// Parse a UUID and tag it with `T`, see [`Uuid::parse`] for details.
+    ///
+    /// # Errors
+    ///
+    /// If `s` is not a valid UUID, or not the version and variant required
+    /// by `T`.
+    #[inline]
+    pub fn parse(s: &str) -> Result<Self, ParseUuidError> {
+        Self::new(Uuid::parse(s)?)
+    }
+}
+
+impl<T: ?Sized> TypedUuid<T> {
+    /// Tag `uuid` with `T`, without checking [`UuidTag::VERSION`].
+    #[inline]
+    pub const fn new_unchecked(uuid: Uuid) -> Self {
+        Self {
+            uuid,
+            _tag: PhantomData,
+        }
+    }
+
+    /// The untagged UUID
+    #[inline]
+    pub const fn uuid(self) -> Uuid {
+        self.uuid
+    }
+}
+
+impl<T: ?Sized> Clone for TypedUuid<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for TypedUuid<T> {}
+
+impl<T: ?Sized> PartialEq for TypedUuid<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.uuid == other.uuid
+    }
+}
+
+impl<T: ?Sized> Eq for TypedUuid<T> {}
+
+impl<T: ?Sized> PartialOrd for TypedUuid<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ?Sized> Ord for TypedUuid<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.uuid.cmp(&other.uuid)
+    }
+}
+
+impl<T: ?Sized> Hash for TypedUuid<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uuid.hash(state)
+    }
+}
+
+/// See [`TypedUuid::new`] for details.
+impl<T: UuidTag + ?Sized> TryFrom<Uuid> for TypedUuid<T> {
+    type Error = ParseUuidError;
+
+    #[inline]
+    fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+        Self::new(uuid)
+    }
+}
+
+uuid_wrapper!(
+    impl[T: ?Sized] TypedUuid<T>,
+    checked[T: UuidTag + ?Sized],
+    |u| u.uuid,
+    as_ref |u| &u.uuid,
+);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{ParseErrorKind, Variant};
+
+    enum User {}
+    impl UuidTag for User {}
+
+    enum Order {}
+    impl UuidTag for Order {
+        const VERSION: Option<Version> = Some(Version::UnixTime);
+    }
+
+    #[test]
+    fn typed() {
+        assert_eq!(
+            core::mem::size_of::<TypedUuid<User>>(),
+            core::mem::size_of::<Uuid>()
+        );
+
+        let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+        let user = TypedUuid::<User>::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+        assert_eq!(user.uuid(), uuid);
+        assert_eq!(format!("{}", user), format!("{}", uuid));
+        assert_eq!(format!("{:#x}", user), format!("{:#x}", uuid));
+        assert_eq!(HashSet::from([user, user]).len(), 1);
+        assert!(TypedUuid::<User>::new(Uuid::nil()).unwrap() < user);
+
+        assert_eq!(
+            TypedUuid::<Order>::try_from(uuid).unwrap_err().kind(),
+            ParseErrorKind::WrongVersion {
+                expected: Version::UnixTime,
+                found: Version::Random
+            }
+        );
+        let order = Uuid::new_v7(0x17F22E279B0, 0, 0);
+
+        // Right version, wrong variant
+        let mut bytes = order.to_bytes();
+        bytes[8] &= 0x7F;
+        assert_eq!(
+            TypedUuid::<Order>::new(Uuid::from_bytes(bytes))
+                .unwrap_err()
+                .kind(),
+            ParseErrorKind::WrongVariant {
+                expected: Variant::Rfc4122,
+                found: Variant::Ncs
+            }
+        );
+        // Without a required version, anything goes
+        TypedUuid::<User>::new(Uuid::from_bytes(bytes)).unwrap();
+
+        assert_eq!(
+            TypedUuid::<Order>::parse(&order.to_string())
+                .unwrap()
+                .uuid(),
+            order
+        );
+    }
+}
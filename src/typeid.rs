@@ -0,0 +1,236 @@
+//! TypeID style prefixed identifiers
+use core::{fmt, str::FromStr};
+
+use crate::{ParseErrorKind, ParseUuidError, Uuid};
+
+/// Lowercase Crockford Base32 alphabet used by TypeID suffixes
+const ALPHABET: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+/// Length of the Base32 suffix
+const SUFFIX_LENGTH: usize = 26;
+
+/// A [TypeID](https://github.com/jetify-com/typeid) style identifier,
+/// a type prefix followed by a [`Uuid`] in lowercase Crockford Base32,
+/// such as `user_01h455vb4pex5vsknk084sn02q`.
+///
+/// Prefixes are up to [`PrefixedId::MAX_PREFIX_LENGTH`] lowercase ASCII
+/// letters and underscores, and must start and end with a letter.
+/// An empty prefix is allowed, and is formatted without the separating
+/// underscore.
+///
+/// TypeIDs are intended to use Version 7 UUIDs, but any UUID is accepted.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{PrefixedId, Uuid};
+/// let id: PrefixedId = "user_01h455vb4pex5vsknk084sn02q".parse().unwrap();
+/// assert_eq!(id.prefix(), "user");
+/// assert_eq!(id.uuid(), Uuid::parse("01890a5d-ac96-774b-bcce-b302099a8057").unwrap());
+/// assert_eq!(id.to_string(), "user_01h455vb4pex5vsknk084sn02q");
+/// ```
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct PrefixedId {
+    /// Prefix bytes, zero padded
+    prefix: [u8; PrefixedId::MAX_PREFIX_LENGTH],
+
+    /// Length of `prefix`
+    len: u8,
+
+    uuid: Uuid,
+}
+
+impl PrefixedId {
+    /// Maximum length of a prefix, in bytes.
+    pub const MAX_PREFIX_LENGTH: usize = 63;
+
+    /// Create a new identifier from `prefix` and `uuid`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{PrefixedId, Uuid};
+    /// let id = PrefixedId::new("user", Uuid::nil()).unwrap();
+    /// assert_eq!(id.to_string(), "user_00000000000000000000000000");
+    ///
+    /// PrefixedId::new("User", Uuid::nil()).unwrap_err();
+    /// PrefixedId::new("_user", Uuid::nil()).unwrap_err();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `prefix` is not a valid prefix,
+    /// see [`ParseErrorKind::InvalidPrefix`].
+    pub fn new(prefix: &str, uuid: Uuid) -> Result<Self, ParseUuidError> {
+        let bytes = prefix.as_bytes();
+        let invalid = |index| Err(ParseUuidError::new(ParseErrorKind::InvalidPrefix { index }));
+
+        if bytes.len() > Self::MAX_PREFIX_LENGTH {
+            return invalid(Self::MAX_PREFIX_LENGTH);
+        }
+        for (i, b) in bytes.iter().enumerate() {
+            let edge = i == 0 || i == bytes.len() - 1;
+            if !(b.is_ascii_lowercase() || (*b == b'_' && !edge)) {
+                return invalid(i);
+            }
+        }
+
+        let mut buf = [0; Self::MAX_PREFIX_LENGTH];
+        buf[..bytes.len()].copy_from_slice(bytes);
+        Ok(Self {
+            prefix: buf,
+            len: bytes.len() as u8,
+            uuid,
+        })
+    }
+
+    /// Parse an identifier from `s`, such as `user_01h455vb4pex5vsknk084sn02q`.
+    ///
+    /// The suffix must be lowercase, and is split from the prefix at the
+    /// last underscore.
+    ///
+    /// # Errors
+    ///
+    /// If the prefix is invalid, or the suffix is not 26 lowercase Crockford
+    /// Base32 digits. Byte indices are relative to the start of `s`.
+    pub fn parse(s: &str) -> Result<Self, ParseUuidError> {
+        let (prefix, suffix, offset) = match s.rsplit_once('_') {
+            // An underscore requires a prefix
+            Some(("", _)) => {
+                return Err(ParseUuidError::new(ParseErrorKind::InvalidPrefix {
+                    index: 0,
+                }))
+            }
+            Some((prefix, suffix)) => (prefix, suffix, prefix.len() + 1),
+            None => ("", s, 0),
+        };
+
+        if suffix.len() != SUFFIX_LENGTH {
+            return Err(ParseUuidError::new(ParseErrorKind::InvalidEncodedLength {
+                len: suffix.len(),
+                expected: SUFFIX_LENGTH,
+            }));
+        }
+        if let Some(i) = suffix.bytes().position(|b| !ALPHABET.contains(&b)) {
+            let index = offset + i;
+            return Err(ParseUuidError::new(ParseErrorKind::InvalidDigit {
+                found: s[index..].chars().next().unwrap_or_default(),
+                index,
+            }));
+        }
+
+        Self::new(prefix, Uuid::parse_base32(suffix)?)
+    }
+
+    /// The type prefix
+    #[inline]
+    pub fn prefix(&self) -> &str {
+        // Safety: Only ever initialized from validated ASCII
+        unsafe { core::str::from_utf8_unchecked(&self.prefix[..self.len as usize]) }
+    }
+
+    /// The UUID
+    #[inline]
+    pub const fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+}
+
+impl fmt::Debug for PrefixedId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrefixedId")
+            .field("prefix", &self.prefix())
+            .field("uuid", &self.uuid)
+            .finish()
+    }
+}
+
+impl fmt::Display for PrefixedId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.len != 0 {
+            write!(f, "{}_", self.prefix())?;
+        }
+        let mut buf = [0; SUFFIX_LENGTH];
+        let suffix = self.uuid.to_base32(&mut buf);
+        suffix.make_ascii_lowercase();
+        f.write_str(suffix)
+    }
+}
+
+impl FromStr for PrefixedId {
+    type Err = ParseUuidError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<PrefixedId> for Uuid {
+    #[inline]
+    fn from(id: PrefixedId) -> Self {
+        id.uuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typeid() {
+        // From the TypeID specification test suite
+        let id = PrefixedId::parse("prefix_01h455vb4pex5vsknk084sn02q").unwrap();
+        assert_eq!(id.prefix(), "prefix");
+        assert_eq!(
+            id.uuid(),
+            Uuid::parse("01890a5d-ac96-774b-bcce-b302099a8057").unwrap()
+        );
+        let id = PrefixedId::parse("pre_fix_00000000000000000000000000").unwrap();
+        assert_eq!(id.prefix(), "pre_fix");
+        assert_eq!(id.uuid(), Uuid::nil());
+        let id = PrefixedId::parse("7zzzzzzzzzzzzzzzzzzzzzzzzz").unwrap();
+        assert_eq!(id.prefix(), "");
+        assert_eq!(id.uuid(), Uuid::max());
+        assert_eq!(id.to_string(), "7zzzzzzzzzzzzzzzzzzzzzzzzz");
+
+        let id = PrefixedId::new(&"a".repeat(63), Uuid::max()).unwrap();
+        assert_eq!(id, id.to_string().parse().unwrap());
+
+        let kind = |s: &str| PrefixedId::parse(s).unwrap_err().kind();
+        assert_eq!(
+            kind("PREFIX_00000000000000000000000000"),
+            ParseErrorKind::InvalidPrefix { index: 0 }
+        );
+        assert_eq!(
+            kind("prefix__00000000000000000000000000"),
+            ParseErrorKind::InvalidPrefix { index: 6 }
+        );
+        assert_eq!(
+            kind("_00000000000000000000000000"),
+            ParseErrorKind::InvalidPrefix { index: 0 }
+        );
+        assert_eq!(
+            kind(&format!("{}_00000000000000000000000000", "a".repeat(64))),
+            ParseErrorKind::InvalidPrefix { index: 63 }
+        );
+        assert_eq!(
+            kind("prefix_0000000000000000000000000"),
+            ParseErrorKind::InvalidEncodedLength {
+                len: 25,
+                expected: 26
+            }
+        );
+        assert_eq!(
+            kind("prefix_0000000000000000000000000L"),
+            ParseErrorKind::InvalidDigit {
+                found: 'L',
+                index: 32
+            }
+        );
+        assert_eq!(
+            kind("prefix_80000000000000000000000000"),
+            ParseErrorKind::Overflow
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! ULID interoperability
+use crate::{ParseUuidError, Uuid, Variant, Version};
+
+impl Uuid {
+    /// Parse a [`Uuid`] from a 26 character ULID string.
+    ///
+    /// ULIDs are a 48-bit millisecond UNIX timestamp followed by 80 random
+    /// bits, in Crockford Base32, see [`Uuid::parse_base32`].
+    /// This is the same layout as Version 7 UUIDs, so the ULID timestamp is
+    /// the [`Uuid::timestamp`] of a Version 7 UUID.
+    ///
+    /// This conversion is lossless, and the result may not be a valid
+    /// Version 7 UUID. See [`Uuid::is_v7_ulid`] to check, and
+    /// [`Uuid::parse_ulid_v7`] to always get one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse_ulid("01FWHE4YDGFK1SHH6W1G60EECF").unwrap();
+    /// assert_eq!(uuid, Uuid::parse("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `s` is not a valid ULID.
+    #[inline]
+    pub fn parse_ulid(s: &str) -> Result<Self, ParseUuidError> {
+        Uuid::parse_base32(s)
+    }
+
+    /// Parse a Version 7 [`Uuid`] from a 26 character ULID string.
+    ///
+    /// The ULID timestamp becomes the Version 7 timestamp, and the version and
+    /// variant bits are set, replacing 6 of the ULID's random bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, UuidTimestamp, Version};
+    /// let uuid = Uuid::parse_ulid_v7("01FWHE4YDG0000000000000000").unwrap();
+    /// assert_eq!(uuid.version(), Version::UnixTime);
+    /// assert_eq!(uuid.timestamp(), Some(UuidTimestamp::UnixMillis(0x17F22E279B0)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `s` is not a valid ULID.
+    #[inline]
+    pub fn parse_ulid_v7(s: &str) -> Result<Self, ParseUuidError> {
+        let mut uuid = Uuid::parse_ulid(s)?;
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::UnixTime);
+        Ok(uuid)
+    }
+
+    /// Whether `s` is a valid ULID that is also a valid Version 7 UUID,
+    /// and so can be converted to one losslessly with [`Uuid::parse_ulid`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// assert!(Uuid::is_v7_ulid("01FWHE4YDGFK1SHH6W1G60EECF"));
+    /// assert!(!Uuid::is_v7_ulid("01FWHE4YDG0000000000000000"));
+    /// assert!(!Uuid::is_v7_ulid("not a ULID"));
+    /// ```
+    #[inline]
+    pub fn is_v7_ulid(s: &str) -> bool {
+        match Uuid::parse_ulid(s) {
+            Ok(uuid) => uuid.version() == Version::UnixTime && uuid.variant() == Variant::Rfc4122,
+            Err(_) => false,
+        }
+    }
+
+    /// Write UUID as a ULID string into `buf`, and return it as a string.
+    ///
+    /// For Version 7 UUIDs, the ULID timestamp is the UUID timestamp.
+    ///
+    /// This is the same as [`Uuid::to_base32`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// # let TIMESTAMP = 0x17F22E279B0;
+    /// let uuid = Uuid::new_v7(TIMESTAMP, 0, 0);
+    /// assert_eq!(uuid.to_ulid(&mut [0; 26]), "01FWHE4YDGE008000000000000");
+    /// ```
+    #[inline]
+    pub fn to_ulid(self, buf: &mut [u8; 26]) -> &mut str {
+        self.to_base32(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rng, UuidTimestamp};
+
+    #[test]
+    fn ulid() {
+        let mut uuids = [Uuid::nil(); 16];
+        Uuid::fill_v7(&mut Rng::from_seed([0; 32]), 0x17F22E279B0, &mut uuids);
+        for uuid in uuids {
+            let ulid = uuid.to_ulid(&mut [0; 26]).to_owned();
+            assert!(Uuid::is_v7_ulid(&ulid));
+            assert_eq!(Uuid::parse_ulid(&ulid).unwrap(), uuid);
+            assert_eq!(Uuid::parse_ulid_v7(&ulid).unwrap(), uuid);
+        }
+
+        // Random ULID bits where the version and variant go
+        let ulid = "01FWHE4YDGZZZZZZZZZZZZZZZZ";
+        assert!(!Uuid::is_v7_ulid(ulid));
+        let uuid = Uuid::parse_ulid_v7(ulid).unwrap();
+        assert_eq!(uuid.version(), Version::UnixTime);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+        assert_eq!(
+            uuid.timestamp(),
+            Some(UuidTimestamp::UnixMillis(0x17F22E279B0))
+        );
+        assert_ne!(uuid, Uuid::parse_ulid(ulid).unwrap());
+    }
+}
@@ -0,0 +1,230 @@
+//! UUIDs guaranteed to be a specific version
+use core::convert::TryFrom;
+
+use rand_chacha::rand_core::RngCore;
+
+use crate::{ParseErrorKind, ParseUuidError, Uuid, Variant, Version};
+
+/// Check `uuid` is an RFC 9562 UUID of `version`
+#[inline]
+pub(crate) fn check(uuid: Uuid, version: Version) -> Result<Uuid, ParseUuidError> {
+    if uuid.variant() != Variant::Rfc4122 {
+        Err(ParseUuidError::new(ParseErrorKind::WrongVariant {
+            expected: Variant::Rfc4122,
+            found: uuid.variant(),
+        }))
+    } else if uuid.version() != version {
+        Err(ParseUuidError::new(ParseErrorKind::WrongVersion {
+            expected: version,
+            found: uuid.version(),
+        }))
+    } else {
+        Ok(uuid)
+    }
+}
+
+/// Define a [`Uuid`] wrapper guaranteed to be `$version`,
+/// and the impls shared by all of them.
+macro_rules! versioned {
+    ($(#[$meta:meta])* $name:ident, $version:expr) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+        #[repr(transparent)]
+        pub struct $name(Uuid);
+
+        impl $name {
+            /// The UUID
+            #[inline]
+            pub const fn uuid(self) -> Uuid {
+                self.0
+            }
+
+            /// The UUID as bytes, see [`Uuid::to_bytes`].
+            #[inline]
+            pub const fn to_bytes(self) -> [u8; 16] {
+                self.0.to_bytes()
+            }
+
+            /// Parse a UUID, see [`Uuid::parse`] for details.
+            ///
+            /// # Errors
+            ///
+            /// If `s` is not a valid UUID, or is the wrong version or variant.
+            #[inline]
+            pub fn parse(s: &str) -> Result<Self, ParseUuidError> {
+                Self::try_from(Uuid::parse(s)?)
+            }
+        }
+
+        /// Fails with [`ParseErrorKind::WrongVariant`] or
+        /// [`ParseErrorKind::WrongVersion`] if `uuid` is not the right
+        /// variant or version.
+        impl TryFrom<Uuid> for $name {
+            type Error = ParseUuidError;
+
+            #[inline]
+            fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+                check(uuid, $version).map(Self)
+            }
+        }
+
+        uuid_wrapper!(impl[] $name, checked[], |u| u.0, as_ref |u| &u.0);
+    };
+}
+
+versioned!(
+    /// A Version 4, random, [`Uuid`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, UuidV4};
+    /// # use std::convert::TryFrom;
+    /// let uuid = UuidV4::try_from(Uuid::new_v4()).unwrap();
+    /// let uuid: UuidV4 = "662aa7c7-7598-4d56-8bcc-a72c30f998a2".parse().unwrap();
+    ///
+    /// UuidV4::try_from(Uuid::nil()).unwrap_err();
+    /// ```
+    UuidV4,
+    Version::Random
+);
+
+impl UuidV4 {
+    /// Create a new UUID, see [`Uuid::new_v4`].
+    #[cfg(feature = "getrandom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    #[inline]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Create a new UUID, see [`Uuid::new_v4_rng`].
+    #[inline]
+    pub fn new_rng<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Self(Uuid::new_v4_rng(rng))
+    }
+}
+
+#[cfg(feature = "getrandom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+impl Default for UuidV4 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+versioned!(
+    /// A Version 5, SHA-1 name based, [`Uuid`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, UuidV5};
+    /// let uuid = UuidV5::new(NAMESPACE_DNS, b"example.com");
+    /// ```
+    UuidV5,
+    Version::Sha1
+);
+
+impl UuidV5 {
+    /// Create a new UUID, see [`Uuid::new_v5`].
+    #[inline]
+    pub fn new(namespace: Uuid, name: &[u8]) -> Self {
+        Self(Uuid::new_v5(namespace, name))
+    }
+}
+
+versioned!(
+    /// A Version 7, UNIX timestamp based, [`Uuid`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::UuidV7;
+    /// let uuid: UuidV7 = "017f22e2-79b0-7cc3-98c4-dc0c0c07398f".parse().unwrap();
+    /// assert_eq!(uuid.unix_millis(), 0x17F22E279B0);
+    ///
+    /// "662aa7c7-7598-4d56-8bcc-a72c30f998a2".parse::<UuidV7>().unwrap_err();
+    /// ```
+    UuidV7,
+    Version::UnixTime
+);
+
+impl UuidV7 {
+    /// Create a new UUID, see [`Uuid::new_v7`].
+    #[inline]
+    pub fn new(unix_ts_ms: u64, rand_a: u16, rand_b: u64) -> Self {
+        Self(Uuid::new_v7(unix_ts_ms, rand_a, rand_b))
+    }
+
+    /// Create a new UUID using the current system time, see [`Uuid::now_v7`].
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "getrandom"))))]
+    #[inline]
+    pub fn now() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// The 48-bit number of milliseconds since the UNIX epoch, 1970-01-01.
+    #[inline]
+    pub fn unix_millis(self) -> u64 {
+        let b = self.0.to_bytes();
+        u64::from_be_bytes([0, 0, b[0], b[1], b[2], b[3], b[4], b[5]])
+    }
+
+    /// The 12-bit `rand_a` field
+    #[inline]
+    pub fn rand_a(self) -> u16 {
+        let b = self.0.to_bytes();
+        u16::from_be_bytes([b[6], b[7]]) & 0xFFF
+    }
+
+    /// The 62-bit `rand_b` field
+    #[inline]
+    pub fn rand_b(self) -> u64 {
+        let b = self.0.to_bytes();
+        u64::from_be_bytes([b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]]) & (u64::MAX >> 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rng, NAMESPACE_DNS};
+
+    #[test]
+    fn versioned() {
+        let v4 = UuidV4::new_rng(&mut Rng::from_seed([0; 32]));
+        assert_eq!(UuidV4::try_from(v4.uuid()), Ok(v4));
+        assert_eq!(UuidV4::parse(&v4.to_string()), Ok(v4));
+        assert!(v4 == v4.uuid() && v4.uuid() == v4);
+
+        let v5 = UuidV5::new(NAMESPACE_DNS, b"example.com");
+        assert_eq!(
+            UuidV4::try_from(v5.uuid()).unwrap_err().kind(),
+            ParseErrorKind::WrongVersion {
+                expected: Version::Random,
+                found: Version::Sha1
+            }
+        );
+
+        let v7 = UuidV7::new(0x17F22E279B0, 0xABC, 0x0123_4567_89AB_CDEF);
+        assert_eq!(v7.unix_millis(), 0x17F22E279B0);
+        assert_eq!(v7.rand_a(), 0xABC);
+        assert_eq!(v7.rand_b(), 0x0123_4567_89AB_CDEF);
+
+        // Right version, wrong variant
+        let mut bytes = v7.to_bytes();
+        bytes[8] &= 0x7F;
+        assert_eq!(
+            UuidV7::try_from(Uuid::from_bytes(bytes))
+                .unwrap_err()
+                .kind(),
+            ParseErrorKind::WrongVariant {
+                expected: Variant::Rfc4122,
+                found: Variant::Ncs
+            }
+        );
+    }
+}
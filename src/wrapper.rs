@@ -0,0 +1,126 @@
+//! Shared impls for [`Uuid`][crate::Uuid] wrapper types
+
+/// Implement the traits shared by all [`Uuid`][crate::Uuid] wrappers,
+/// forwarding to the wrapped UUID.
+///
+/// - `impl[..]` are the generics for every impl.
+/// - `checked[..]` are the generics for impls that construct the wrapper,
+///   which must have a `parse` method.
+/// - `|s| get` returns the wrapped UUID by value from `s: &Self`.
+/// - `as_ref |s| expr` optionally returns a reference to it, for `AsRef`.
+///
+/// The wrapper must implement `TryFrom<Uuid, Error = ParseUuidError>`.
+macro_rules! uuid_wrapper {
+    (
+        impl[$($g:tt)*] $name:ident $(<$($p:ident),*>)?,
+        checked[$($gc:tt)*],
+        |$s:ident| $get:expr,
+        as_ref |$r:ident| $as_ref:expr $(,)?
+    ) => {
+        uuid_wrapper!(impl[$($g)*] $name $(<$($p),*>)?, checked[$($gc)*], |$s| $get);
+
+        impl<$($g)*> AsRef<$crate::Uuid> for $name $(<$($p),*>)? {
+            #[inline]
+            fn as_ref(&self) -> &$crate::Uuid {
+                let $r = self;
+                $as_ref
+            }
+        }
+    };
+
+    (
+        impl[$($g:tt)*] $name:ident $(<$($p:ident),*>)?,
+        checked[$($gc:tt)*],
+        |$s:ident| $get:expr $(,)?
+    ) => {
+        impl<$($g)*> From<$name $(<$($p),*>)?> for $crate::Uuid {
+            #[inline]
+            fn from(uuid: $name $(<$($p),*>)?) -> Self {
+                let $s = &uuid;
+                $get
+            }
+        }
+
+        impl<$($g)*> PartialEq<$crate::Uuid> for $name $(<$($p),*>)? {
+            #[inline]
+            fn eq(&self, other: &$crate::Uuid) -> bool {
+                let $s = self;
+                $get == *other
+            }
+        }
+
+        impl<$($g)*> PartialEq<$name $(<$($p),*>)?> for $crate::Uuid {
+            #[inline]
+            fn eq(&self, other: &$name $(<$($p),*>)?) -> bool {
+                let $s = other;
+                *self == $get
+            }
+        }
+
+        /// See the `parse` method for details.
+        impl<$($gc)*> core::str::FromStr for $name $(<$($p),*>)? {
+            type Err = $crate::ParseUuidError;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+
+        impl<$($g)*> core::fmt::Debug for $name $(<$($p),*>)? {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let $s = self;
+                f.debug_tuple(stringify!($name)).field(&$get).finish()
+            }
+        }
+
+        /// See [`Uuid`][crate::Uuid]'s `Display` for details.
+        impl<$($g)*> core::fmt::Display for $name $(<$($p),*>)? {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let $s = self;
+                core::fmt::Display::fmt(&$get, f)
+            }
+        }
+
+        /// See [`Uuid`][crate::Uuid]'s `LowerHex` for details.
+        impl<$($g)*> core::fmt::LowerHex for $name $(<$($p),*>)? {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let $s = self;
+                core::fmt::LowerHex::fmt(&$get, f)
+            }
+        }
+
+        /// See [`Uuid`][crate::Uuid]'s `UpperHex` for details.
+        impl<$($g)*> core::fmt::UpperHex for $name $(<$($p),*>)? {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let $s = self;
+                core::fmt::UpperHex::fmt(&$get, f)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+        impl<$($g)*> serde::Serialize for $name $(<$($p),*>)? {
+            #[inline]
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let $s = self;
+                serde::Serialize::serialize(&$get, serializer)
+            }
+        }
+
+        /// Fails if the UUID can't be converted with `TryFrom<Uuid>`
+        #[cfg(feature = "serde")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+        impl<'de, $($gc)*> serde::Deserialize<'de> for $name $(<$($p),*>)? {
+            #[inline]
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let uuid = <$crate::Uuid as serde::Deserialize>::deserialize(deserializer)?;
+                <Self as core::convert::TryFrom<$crate::Uuid>>::try_from(uuid)
+                    .map_err(<D::Error as serde::de::Error>::custom)
+            }
+        }
+    };
+}